@@ -0,0 +1,20 @@
+//! Error codes only the Chef contract reverts with.
+
+use casper_types::ApiError;
+
+/// Errors of the Chef contract, mapped to `ApiError::User`. Codes start at 1000,
+/// clear of the `StakingError` codes the Chef contract reverts with as well.
+#[repr(u16)]
+#[derive(Clone, Copy)]
+pub enum ChefError {
+    /// Chef has no pool with the requested id.
+    UnknownPool = 1000,
+    /// Chef already has a pool for the Stake Token.
+    PoolExists = 1001,
+}
+
+impl From<ChefError> for ApiError {
+    fn from(error: ChefError) -> Self {
+        ApiError::User(error as u16)
+    }
+}
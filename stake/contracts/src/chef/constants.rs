@@ -0,0 +1,91 @@
+//! Constants used by the Chef contract.
+
+pub const CHEF_CONTRACT_KEY_NAME: &str = "chef_contract";
+
+// Named keys
+
+pub const REWARD_TOKEN_HASH_KEY_NAME: &str = "reward_token_hash";
+
+pub const REWARD_RATE_KEY_NAME: &str = "reward_rate";
+
+pub const PERIOD_FINISH_KEY_NAME: &str = "period_finish";
+
+pub const LAST_UPDATE_KEY_NAME: &str = "last_update_time";
+
+pub const REWARD_RESERVE_KEY_NAME: &str = "reward_reserve";
+
+pub const OUTSTANDING_REWARDS_KEY_NAME: &str = "outstanding_rewards";
+
+pub const TOTAL_ALLOC_POINT_KEY_NAME: &str = "total_alloc_point";
+
+pub const POOL_COUNT_KEY_NAME: &str = "pool_count";
+
+pub const OWNER_KEY_NAME: &str = "owner";
+
+pub const PENDING_OWNER_KEY_NAME: &str = "pending_owner";
+
+// Dictionaries of pools, the dictionary item key is the pool id
+
+pub const POOL_STAKE_TOKENS_KEY_NAME: &str = "pool_stake_tokens";
+
+pub const POOL_ALLOC_POINTS_KEY_NAME: &str = "pool_alloc_points";
+
+pub const POOL_TOTAL_SUPPLIES_KEY_NAME: &str = "pool_total_supplies";
+
+pub const POOL_LAST_UPDATE_KEY_NAME: &str = "pool_last_update_time";
+
+pub const POOL_REWARD_PER_TOKEN_STORED_KEY_NAME: &str = "pool_reward_per_token_stored";
+
+pub const POOL_REWARD_PER_TOKEN_DUST_KEY_NAME: &str = "pool_reward_per_token_dust";
+
+// Dictionaries of users, the dictionary item key is the address and the pool id
+
+pub const BALANCES_KEY_NAME: &str = "balances";
+
+pub const REWARDS_KEY_NAME: &str = "rewards";
+
+pub const USER_REWARD_PER_TOKEN_PAID_KEY_NAME: &str = "user_reward_per_token_paid";
+
+// Entry points
+
+pub const ADD_POOL_ENTRY_POINT_NAME: &str = "add_pool";
+
+pub const SET_POOL_ENTRY_POINT_NAME: &str = "set_pool";
+
+pub const NOTIFY_REWARD_AMOUNT_ENTRY_POINT_NAME: &str = "notify_reward_amount";
+
+pub const SET_REWARD_RATE_ENTRY_POINT_NAME: &str = "set_reward_rate";
+
+pub const STAKE_ENTRY_POINT_NAME: &str = "stake";
+
+pub const WITHDRAW_ENTRY_POINT_NAME: &str = "withdraw";
+
+pub const GET_REWARD_ENTRY_POINT_NAME: &str = "get_reward";
+
+pub const EMERGENCY_WITHDRAW_ENTRY_POINT_NAME: &str = "emergency_withdraw";
+
+pub const TRANSFER_OWNERSHIP_ENTRY_POINT_NAME: &str = "transfer_ownership";
+
+pub const ACCEPT_OWNERSHIP_ENTRY_POINT_NAME: &str = "accept_ownership";
+
+// Views
+
+pub const BALANCE_OF_ENTRY_POINT_NAME: &str = "balance_of";
+
+pub const EARNED_ENTRY_POINT_NAME: &str = "earned";
+
+// Runtime argument names
+
+pub const POOL_ID_KEY_NAME: &str = "pool_id";
+
+pub const STAKE_TOKEN_HASH_KEY_NAME: &str = "stake_token_hash";
+
+pub const ALLOC_POINT_KEY_NAME: &str = "alloc_point";
+
+pub const AMOUNT_KEY_NAME: &str = "amount";
+
+pub const DURATION_KEY_NAME: &str = "duration";
+
+pub const ADDRESS_KEY_NAME: &str = "address";
+
+pub const NEW_OWNER_KEY_NAME: &str = "new_owner";
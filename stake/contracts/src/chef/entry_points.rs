@@ -0,0 +1,188 @@
+use alloc::{string::String, vec};
+
+use crate::constants::{
+    ADD_POOL_ENTRY_POINT_NAME, SET_POOL_ENTRY_POINT_NAME, NOTIFY_REWARD_AMOUNT_ENTRY_POINT_NAME,
+    SET_REWARD_RATE_ENTRY_POINT_NAME,
+    STAKE_ENTRY_POINT_NAME, WITHDRAW_ENTRY_POINT_NAME, GET_REWARD_ENTRY_POINT_NAME,
+    EMERGENCY_WITHDRAW_ENTRY_POINT_NAME, TRANSFER_OWNERSHIP_ENTRY_POINT_NAME,
+    ACCEPT_OWNERSHIP_ENTRY_POINT_NAME, NEW_OWNER_KEY_NAME,
+    BALANCE_OF_ENTRY_POINT_NAME, EARNED_ENTRY_POINT_NAME, POOL_ID_KEY_NAME,
+    STAKE_TOKEN_HASH_KEY_NAME, ALLOC_POINT_KEY_NAME, AMOUNT_KEY_NAME, ADDRESS_KEY_NAME,
+    REWARD_RATE_KEY_NAME, DURATION_KEY_NAME
+    };
+
+use casper_erc20::Address;
+
+use casper_types::{
+    U256, Key, CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Parameter
+    };
+
+fn add_pool() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ADD_POOL_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(STAKE_TOKEN_HASH_KEY_NAME, Key::cl_type()),
+            Parameter::new(ALLOC_POINT_KEY_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn set_pool() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_POOL_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(POOL_ID_KEY_NAME, u32::cl_type()),
+            Parameter::new(ALLOC_POINT_KEY_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn notify_reward_amount() -> EntryPoint {
+    EntryPoint::new(
+        String::from(NOTIFY_REWARD_AMOUNT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(AMOUNT_KEY_NAME, U256::cl_type()),
+            Parameter::new(DURATION_KEY_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn set_reward_rate() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_REWARD_RATE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(REWARD_RATE_KEY_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn stake() -> EntryPoint {
+    EntryPoint::new(
+        String::from(STAKE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(POOL_ID_KEY_NAME, u32::cl_type()),
+            Parameter::new(AMOUNT_KEY_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn withdraw() -> EntryPoint {
+    EntryPoint::new(
+        String::from(WITHDRAW_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(POOL_ID_KEY_NAME, u32::cl_type()),
+            Parameter::new(AMOUNT_KEY_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn get_reward() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_REWARD_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(POOL_ID_KEY_NAME, u32::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn emergency_withdraw() -> EntryPoint {
+    EntryPoint::new(
+        String::from(EMERGENCY_WITHDRAW_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(POOL_ID_KEY_NAME, u32::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn balance_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BALANCE_OF_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(POOL_ID_KEY_NAME, u32::cl_type()),
+            Parameter::new(ADDRESS_KEY_NAME, Address::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn transfer_ownership() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TRANSFER_OWNERSHIP_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(NEW_OWNER_KEY_NAME, Address::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn accept_ownership() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ACCEPT_OWNERSHIP_ENTRY_POINT_NAME),
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn earned() -> EntryPoint {
+    EntryPoint::new(
+        String::from(EARNED_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(POOL_ID_KEY_NAME, u32::cl_type()),
+            Parameter::new(ADDRESS_KEY_NAME, Address::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the default set of Chef entry points.
+pub(crate) fn default() -> EntryPoints {
+    
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(add_pool());
+    entry_points.add_entry_point(set_pool());
+    entry_points.add_entry_point(notify_reward_amount());
+    entry_points.add_entry_point(set_reward_rate());
+    entry_points.add_entry_point(stake());
+    entry_points.add_entry_point(withdraw());
+    entry_points.add_entry_point(get_reward());
+    entry_points.add_entry_point(emergency_withdraw());
+    entry_points.add_entry_point(transfer_ownership());
+    entry_points.add_entry_point(accept_ownership());
+    entry_points.add_entry_point(balance_of());
+    entry_points.add_entry_point(earned());
+
+    entry_points
+    
+}
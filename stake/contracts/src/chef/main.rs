@@ -0,0 +1,705 @@
+//! Chef contract: many staking pools in one contract, every pool pays the same Reward Token.
+//!
+//! The owner funds reward periods with `notify_reward_amount`, "reward_rate" Reward Tokens are
+//! emitted per time unit until the period finishes and split across pools by their allocation
+//! points, inside a pool they are shared by stakers like in the Stake contract.
+//! Rewards are paid from the reward reserve, which always covers the rewards owed and promised.
+
+#![no_main]
+#![no_std]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+// Shared with the Stake contract
+#[allow(dead_code)]
+#[path = "../helpers.rs"]
+mod helpers;
+#[allow(dead_code)]
+#[path = "../error.rs"]
+mod error;
+#[allow(dead_code)]
+#[path = "../constants.rs"]
+mod staking_constants;
+
+mod entry_points;
+mod named_keys;
+mod chef_error;
+pub mod constants;
+
+use crate::helpers::{
+    set_key, get_key, get_immediate_caller_address, get_self_address, dictionary_get, make_indexed_item_key
+};
+
+use crate::constants::{
+    CHEF_CONTRACT_KEY_NAME, REWARD_TOKEN_HASH_KEY_NAME, REWARD_RATE_KEY_NAME, PERIOD_FINISH_KEY_NAME,
+    LAST_UPDATE_KEY_NAME, REWARD_RESERVE_KEY_NAME, OUTSTANDING_REWARDS_KEY_NAME,
+    TOTAL_ALLOC_POINT_KEY_NAME, POOL_COUNT_KEY_NAME, OWNER_KEY_NAME, PENDING_OWNER_KEY_NAME,
+    POOL_STAKE_TOKENS_KEY_NAME, POOL_ALLOC_POINTS_KEY_NAME, POOL_TOTAL_SUPPLIES_KEY_NAME,
+    POOL_LAST_UPDATE_KEY_NAME, POOL_REWARD_PER_TOKEN_STORED_KEY_NAME,
+    POOL_REWARD_PER_TOKEN_DUST_KEY_NAME, BALANCES_KEY_NAME, REWARDS_KEY_NAME,
+    USER_REWARD_PER_TOKEN_PAID_KEY_NAME, POOL_ID_KEY_NAME, STAKE_TOKEN_HASH_KEY_NAME,
+    ALLOC_POINT_KEY_NAME, AMOUNT_KEY_NAME, DURATION_KEY_NAME, ADDRESS_KEY_NAME, NEW_OWNER_KEY_NAME,
+};
+use crate::staking_constants::REWARD_PRECISION;
+
+use crate::error::StakingError;
+use crate::chef_error::ChefError;
+
+use alloc::string::{String, ToString};
+
+use casper_erc20::{ Address,
+    constants::{
+        TRANSFER_ENTRY_POINT_NAME, TRANSFER_FROM_ENTRY_POINT_NAME, ALLOWANCE_ENTRY_POINT_NAME,
+        OWNER_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME,
+        SPENDER_RUNTIME_ARG_NAME}
+    };
+
+use casper_contract::{contract_api::{runtime, storage}, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    bytesrepr::{FromBytes, ToBytes}, contracts::NamedKeys, CLTyped, U256, ContractHash, Key, URef,
+    RuntimeArgs, runtime_args, CLValue};
+
+#[no_mangle]
+fn call() {
+
+    let contract_name: String = runtime::get_named_arg(CHEF_CONTRACT_KEY_NAME);
+    let contract_hash_key_name = contract_name.clone();
+    let contract_package_hash_key_name = contract_name.clone() + "_package_hash";
+
+    let reward_token_key: Key = runtime::get_named_arg(REWARD_TOKEN_HASH_KEY_NAME);
+
+    // Installer becomes the owner of the contract
+    let owner: Address = Address::from(runtime::get_caller());
+
+    let named_keys: NamedKeys = named_keys::default(
+        contract_name,
+        reward_token_key,
+        owner
+    );
+
+    // We store contract on-chain
+    let (contract_hash, _version) = storage::new_locked_contract(
+        entry_points::default(),
+        Some(named_keys),
+        Some(contract_package_hash_key_name),
+        None
+    );
+
+    runtime::put_key(contract_hash_key_name.as_str(), Key::from(contract_hash));
+}
+
+/// Adds a pool of `stake_token` getting `alloc_point` shares of "reward_rate"
+#[no_mangle]
+pub extern "C" fn add_pool() {
+
+    only_owner();
+
+    let stake_token: Key = runtime::get_named_arg(STAKE_TOKEN_HASH_KEY_NAME);
+    let alloc_point: U256 = runtime::get_named_arg(ALLOC_POINT_KEY_NAME);
+
+    let pool_count: u32 = get_key(POOL_COUNT_KEY_NAME).unwrap_or_revert();
+
+    // Two pools of one token would share the same balance of the contract
+    for pool_id in 0..pool_count {
+        if pool_read::<Key>(POOL_STAKE_TOKENS_KEY_NAME, pool_id) == Some(stake_token) {
+            runtime::revert(ChefError::PoolExists);
+        }
+    }
+
+    // Shares of every pool change with the total allocation points
+    mass_update_pools();
+
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+
+    pool_write(POOL_STAKE_TOKENS_KEY_NAME, pool_count, stake_token);
+    pool_write(POOL_ALLOC_POINTS_KEY_NAME, pool_count, alloc_point);
+    pool_write(POOL_TOTAL_SUPPLIES_KEY_NAME, pool_count, U256::from(0));
+    pool_write(POOL_LAST_UPDATE_KEY_NAME, pool_count, current_block_time);
+    pool_write(POOL_REWARD_PER_TOKEN_STORED_KEY_NAME, pool_count, U256::from(0));
+    pool_write(POOL_REWARD_PER_TOKEN_DUST_KEY_NAME, pool_count, U256::from(0));
+
+    named_key_add(alloc_point, TOTAL_ALLOC_POINT_KEY_NAME);
+    set_key(POOL_COUNT_KEY_NAME, pool_count + 1);
+}
+
+/// Changes the allocation points of `pool_id`
+#[no_mangle]
+pub extern "C" fn set_pool() {
+
+    only_owner();
+
+    let pool_id: u32 = runtime::get_named_arg(POOL_ID_KEY_NAME);
+    let alloc_point: U256 = runtime::get_named_arg(ALLOC_POINT_KEY_NAME);
+
+    check_pool(pool_id);
+
+    mass_update_pools();
+
+    let old_alloc_point: U256 = pool_read(POOL_ALLOC_POINTS_KEY_NAME, pool_id).unwrap_or_default();
+    let total_alloc_point: U256 = get_key(TOTAL_ALLOC_POINT_KEY_NAME).unwrap_or_revert();
+
+    set_key(TOTAL_ALLOC_POINT_KEY_NAME, total_alloc_point - old_alloc_point + alloc_point);
+    pool_write(POOL_ALLOC_POINTS_KEY_NAME, pool_id, alloc_point);
+}
+
+/// Starts a reward period of `duration` paying `amount` of Reward Token pulled from the owner,
+/// rewards not distributed yet in the running period are rolled into the new one
+#[no_mangle]
+pub extern "C" fn notify_reward_amount() {
+
+    only_owner();
+
+    let amount: U256 = runtime::get_named_arg(AMOUNT_KEY_NAME);
+    let duration: U256 = runtime::get_named_arg(DURATION_KEY_NAME);
+
+    if duration.is_zero() {
+        runtime::revert(StakingError::ZeroDuration);
+    }
+
+    let funder: Address = get_immediate_caller_address().unwrap_or_revert();
+    let reward_token: Key = get_key(REWARD_TOKEN_HASH_KEY_NAME).unwrap_or_revert();
+
+    // accrue everything owed under the old rate before it changes
+    mass_update_pools();
+
+    erc20_transfer_from(reward_token, funder, amount);
+    named_key_add(amount, REWARD_RESERVE_KEY_NAME);
+
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+
+    let leftover: U256 = undistributed_rewards();
+    let reward_rate: U256 = amount
+        .checked_add(leftover)
+        .ok_or(StakingError::Overflow).unwrap_or_revert()
+        / duration;
+
+    // The reserve should cover the whole period on top of the rewards already owed
+    promise_rewards(
+        leftover,
+        reward_rate
+            .checked_mul(duration)
+            .ok_or(StakingError::Overflow).unwrap_or_revert()
+    );
+
+    set_key(REWARD_RATE_KEY_NAME, reward_rate);
+    set_key(
+        PERIOD_FINISH_KEY_NAME,
+        current_block_time
+            .checked_add(duration)
+            .ok_or(StakingError::Overflow).unwrap_or_revert()
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn set_reward_rate() {
+
+    only_owner();
+
+    let reward_rate: U256 = runtime::get_named_arg(REWARD_RATE_KEY_NAME);
+
+    // keep everything accrued under the old rate
+    mass_update_pools();
+
+    // The rest of the running period has to be covered by the reward reserve
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+    let period_finish: U256 = get_key(PERIOD_FINISH_KEY_NAME).unwrap_or_revert();
+
+    if period_finish > current_block_time {
+        promise_rewards(
+            undistributed_rewards(),
+            reward_rate
+                .checked_mul(period_finish - current_block_time)
+                .ok_or(StakingError::Overflow).unwrap_or_revert()
+        );
+    }
+
+    set_key(REWARD_RATE_KEY_NAME, reward_rate);
+}
+
+#[no_mangle]
+pub extern "C" fn stake() {
+
+    let pool_id: u32 = runtime::get_named_arg(POOL_ID_KEY_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_KEY_NAME);
+
+    if amount.is_zero() {
+        runtime::revert(StakingError::ZeroAmount);
+    }
+
+    check_pool(pool_id);
+
+    let staker: Address = get_immediate_caller_address().unwrap_or_revert();
+    let stake_token: Key = pool_read(POOL_STAKE_TOKENS_KEY_NAME, pool_id).unwrap_or_revert();
+
+    update_reward(staker, pool_id);
+
+    // update total supply of the pool and balance of caller
+    pool_add(POOL_TOTAL_SUPPLIES_KEY_NAME, pool_id, amount);
+    user_add(BALANCES_KEY_NAME, staker, pool_id, amount);
+
+    // Transfer `amount` of Stake Token from caller to the chef contract
+    erc20_transfer_from(stake_token, staker, amount);
+}
+
+#[no_mangle]
+pub extern "C" fn withdraw() {
+
+    let pool_id: u32 = runtime::get_named_arg(POOL_ID_KEY_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_KEY_NAME);
+
+    if amount.is_zero() {
+        runtime::revert(StakingError::ZeroAmount);
+    }
+
+    check_pool(pool_id);
+
+    let staker: Address = get_immediate_caller_address().unwrap_or_revert();
+    let stake_token: Key = pool_read(POOL_STAKE_TOKENS_KEY_NAME, pool_id).unwrap_or_revert();
+
+    update_reward(staker, pool_id);
+
+    // update balance of caller and total supply of the pool
+    user_sub(BALANCES_KEY_NAME, staker, pool_id, amount);
+    pool_sub(POOL_TOTAL_SUPPLIES_KEY_NAME, pool_id, amount);
+
+    // Transfer `amount` of Stake Token from the chef contract to caller
+    erc20_transfer(stake_token, staker, amount);
+
+    pay_reward(staker, pool_id);
+}
+
+#[no_mangle]
+pub extern "C" fn get_reward() {
+
+    let pool_id: u32 = runtime::get_named_arg(POOL_ID_KEY_NAME);
+
+    check_pool(pool_id);
+
+    let staker: Address = get_immediate_caller_address().unwrap_or_revert();
+
+    update_reward(staker, pool_id);
+
+    pay_reward(staker, pool_id);
+}
+
+/// Returns the whole stake of the caller in `pool_id` without touching the Reward Token,
+/// all the rewards accrued in the pool so far are forfeited
+#[no_mangle]
+pub extern "C" fn emergency_withdraw() {
+
+    let pool_id: u32 = runtime::get_named_arg(POOL_ID_KEY_NAME);
+
+    check_pool(pool_id);
+
+    let staker: Address = get_immediate_caller_address().unwrap_or_revert();
+    let stake_token: Key = pool_read(POOL_STAKE_TOKENS_KEY_NAME, pool_id).unwrap_or_revert();
+    let balance: U256 = user_read(BALANCES_KEY_NAME, staker, pool_id);
+
+    if balance.is_zero() {
+        runtime::revert(StakingError::ZeroAmount);
+    }
+
+    // stakers left keep what accrued while the stake was still in the pool
+    let reward_per_token_stored: U256 = update_pool(pool_id);
+
+    // forfeited rewards are not owed anymore, they stay in the reserve
+    let forfeited: U256 = user_read(REWARDS_KEY_NAME, staker, pool_id)
+        .checked_add(accrued_reward(staker, pool_id, reward_per_token_stored))
+        .ok_or(StakingError::Overflow).unwrap_or_revert();
+    named_key_sub(forfeited, OUTSTANDING_REWARDS_KEY_NAME);
+
+    user_write(BALANCES_KEY_NAME, staker, pool_id, U256::from(0));
+    user_write(REWARDS_KEY_NAME, staker, pool_id, U256::from(0));
+    user_write(USER_REWARD_PER_TOKEN_PAID_KEY_NAME, staker, pool_id, reward_per_token_stored);
+    pool_sub(POOL_TOTAL_SUPPLIES_KEY_NAME, pool_id, balance);
+
+    erc20_transfer(stake_token, staker, balance);
+}
+
+/// Transfers the rewards of `pool_id` credited to `staker`.
+/// When the reserve can't cover all of them it is paid out and the rest stays owed
+fn pay_reward(staker: Address, pool_id: u32) {
+
+    let staker_reward: U256 = user_read(REWARDS_KEY_NAME, staker, pool_id);
+
+    if staker_reward.is_zero() {
+        return;
+    }
+
+    // Reward Tokens staked in pools are not part of the reserve
+    let reward_token: Key = get_key(REWARD_TOKEN_HASH_KEY_NAME).unwrap_or_revert();
+    let amount: U256 = take_from_reward_reserve(staker_reward);
+
+    if amount.is_zero() {
+        return;
+    }
+
+    // keep the unpaid part of the rewards owed, withdrawals never wait for a top up
+    user_write(REWARDS_KEY_NAME, staker, pool_id, staker_reward - amount);
+
+    erc20_transfer(reward_token, staker, amount);
+}
+
+/// Offers the ownership of the Chef to `new_owner`, a governance contract for instance
+#[no_mangle]
+pub extern "C" fn transfer_ownership() {
+
+    only_owner();
+
+    let new_owner: Address = runtime::get_named_arg(NEW_OWNER_KEY_NAME);
+
+    // New owner has to accept the ownership before it is transferred
+    set_key(PENDING_OWNER_KEY_NAME, Some(new_owner));
+}
+
+#[no_mangle]
+pub extern "C" fn accept_ownership() {
+
+    let caller: Address = get_immediate_caller_address().unwrap_or_revert();
+    let pending_owner: Option<Address> = get_key(PENDING_OWNER_KEY_NAME).unwrap_or_revert();
+
+    if pending_owner != Some(caller) {
+        runtime::revert(StakingError::Unauthorized);
+    }
+
+    set_key(OWNER_KEY_NAME, caller);
+    set_key::<Option<Address>>(PENDING_OWNER_KEY_NAME, None);
+}
+
+/// Reverts unless the immediate caller is the owner of the contract
+fn only_owner() {
+    let caller: Address = get_immediate_caller_address().unwrap_or_revert();
+    let owner: Address = get_key(OWNER_KEY_NAME).unwrap_or_revert();
+
+    if caller != owner {
+        runtime::revert(StakingError::Unauthorized);
+    }
+}
+
+/// Reverts unless `pool_id` was added
+fn check_pool(pool_id: u32) {
+    let pool_count: u32 = get_key(POOL_COUNT_KEY_NAME).unwrap_or_revert();
+
+    if pool_id >= pool_count {
+        runtime::revert(ChefError::UnknownPool);
+    }
+}
+
+/// Rewards the running period has not distributed yet, for all the pools
+fn undistributed_rewards() -> U256 {
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+    let period_finish: U256 = get_key(PERIOD_FINISH_KEY_NAME).unwrap_or_revert();
+
+    if period_finish <= current_block_time {
+        return U256::from(0);
+    }
+
+    let reward_rate: U256 = get_key(REWARD_RATE_KEY_NAME).unwrap_or_revert();
+
+    reward_rate
+        .checked_mul(period_finish - current_block_time)
+        .ok_or(StakingError::Overflow).unwrap_or_revert()
+}
+
+/// Replaces the `undistributed` rewards of the running period by `promised` ones.
+/// The reserve has to cover them on top of the rewards already owed to stakers
+fn promise_rewards(undistributed: U256, promised: U256) {
+    let outstanding_rewards: U256 = get_key(OUTSTANDING_REWARDS_KEY_NAME).unwrap_or_revert();
+    let reward_reserve: U256 = get_key(REWARD_RESERVE_KEY_NAME).unwrap_or_revert();
+
+    let outstanding_rewards: U256 = outstanding_rewards
+        .checked_sub(undistributed)
+        .and_then(|owed| owed.checked_add(promised))
+        .ok_or(StakingError::Overflow).unwrap_or_revert();
+
+    if outstanding_rewards > reward_reserve {
+        runtime::revert(StakingError::RewardTooHigh);
+    }
+
+    set_key(OUTSTANDING_REWARDS_KEY_NAME, outstanding_rewards);
+}
+
+/// Takes up to `amount` out of the reserve to pay owed rewards,
+/// returns the part the reserve could cover
+fn take_from_reward_reserve(amount: U256) -> U256 {
+    let reward_reserve: U256 = get_key(REWARD_RESERVE_KEY_NAME).unwrap_or_revert();
+
+    let amount: U256 = amount.min(reward_reserve);
+
+    set_key(REWARD_RESERVE_KEY_NAME, reward_reserve - amount);
+    named_key_sub(amount, OUTSTANDING_REWARDS_KEY_NAME);
+
+    amount
+}
+
+// Views, results are returned to the caller with `runtime::ret`
+
+#[no_mangle]
+pub extern "C" fn balance_of() {
+
+    let pool_id: u32 = runtime::get_named_arg(POOL_ID_KEY_NAME);
+    let address: Address = runtime::get_named_arg(ADDRESS_KEY_NAME);
+
+    check_pool(pool_id);
+
+    let balance: U256 = user_read(BALANCES_KEY_NAME, address, pool_id);
+
+    runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
+}
+
+/// Rewards of `pool_id` `address` can claim at the current block time, nothing is written
+#[no_mangle]
+pub extern "C" fn earned() {
+
+    let pool_id: u32 = runtime::get_named_arg(POOL_ID_KEY_NAME);
+    let address: Address = runtime::get_named_arg(ADDRESS_KEY_NAME);
+
+    check_pool(pool_id);
+
+    let (reward_per_token, _) = reward_per_token_at(pool_id);
+    let earned: U256 = user_read(REWARDS_KEY_NAME, address, pool_id)
+        .checked_add(accrued_reward(address, pool_id, reward_per_token))
+        .ok_or(StakingError::Overflow).unwrap_or_revert();
+
+    runtime::ret(CLValue::from_t(earned).unwrap_or_revert());
+}
+
+/// Checkpoints the running sum of every pool.
+/// Rewards emitted while no pool has allocation points are not owed to anybody
+fn mass_update_pools() {
+    let pool_count: u32 = get_key(POOL_COUNT_KEY_NAME).unwrap_or_revert();
+
+    for pool_id in 0..pool_count {
+        update_pool(pool_id);
+    }
+
+    // allocation points only change right after this update
+    let total_alloc_point: U256 = get_key(TOTAL_ALLOC_POINT_KEY_NAME).unwrap_or_revert();
+    if total_alloc_point.is_zero() {
+        let last_update_time: U256 = get_key(LAST_UPDATE_KEY_NAME).unwrap_or_revert();
+        named_key_sub(emitted_rewards(last_update_time), OUTSTANDING_REWARDS_KEY_NAME);
+    }
+
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+    set_key(LAST_UPDATE_KEY_NAME, current_block_time);
+}
+
+/// Checkpoints the running sum of `pool_id` and its last update time
+fn update_pool(pool_id: u32) -> U256 {
+
+    // Nobody earns the rewards of a pool while nothing is staked in it, they are not owed anymore
+    let total_supply: U256 = pool_read(POOL_TOTAL_SUPPLIES_KEY_NAME, pool_id).unwrap_or_default();
+    if total_supply.is_zero() {
+        let unassigned: U256 = pool_scaled_rewards(pool_id) / U256::from(REWARD_PRECISION);
+
+        named_key_sub(unassigned, OUTSTANDING_REWARDS_KEY_NAME);
+    }
+
+    let (reward_per_token_stored, reward_per_token_dust) = reward_per_token_at(pool_id);
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+
+    pool_write(POOL_REWARD_PER_TOKEN_STORED_KEY_NAME, pool_id, reward_per_token_stored);
+    pool_write(POOL_REWARD_PER_TOKEN_DUST_KEY_NAME, pool_id, reward_per_token_dust);
+    pool_write(POOL_LAST_UPDATE_KEY_NAME, pool_id, current_block_time);
+
+    reward_per_token_stored
+}
+
+/// Credits `staker` with the rewards of `pool_id` accrued since the last checkpoint
+fn update_reward(staker: Address, pool_id: u32) {
+
+    let reward_per_token_stored: U256 = update_pool(pool_id);
+
+    user_add(REWARDS_KEY_NAME, staker, pool_id, accrued_reward(staker, pool_id, reward_per_token_stored));
+    user_write(USER_REWARD_PER_TOKEN_PAID_KEY_NAME, staker, pool_id, reward_per_token_stored);
+}
+
+/// Rewards emitted for all the pools from `from` until now, or until the reward period finished
+fn emitted_rewards(from: U256) -> U256 {
+
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+    let period_finish: U256 = get_key(PERIOD_FINISH_KEY_NAME).unwrap_or_revert();
+    let time_reward_applicable: U256 = current_block_time.min(period_finish);
+
+    if time_reward_applicable <= from {
+        return U256::from(0);
+    }
+
+    let reward_rate: U256 = get_key(REWARD_RATE_KEY_NAME).unwrap_or_revert();
+
+    reward_rate
+        .checked_mul(time_reward_applicable - from)
+        .ok_or(StakingError::Overflow).unwrap_or_revert()
+}
+
+/// Share of `pool_id` in the rewards emitted since its last update, scaled by REWARD_PRECISION
+/// before it is split, so the allocation share doesn't round on its own
+fn pool_scaled_rewards(pool_id: u32) -> U256 {
+
+    let last_update_time: U256 = pool_read(POOL_LAST_UPDATE_KEY_NAME, pool_id).unwrap_or_default();
+    let alloc_point: U256 = pool_read(POOL_ALLOC_POINTS_KEY_NAME, pool_id).unwrap_or_default();
+    let total_alloc_point: U256 = get_key(TOTAL_ALLOC_POINT_KEY_NAME).unwrap_or_revert();
+
+    if total_alloc_point.is_zero() {
+        return U256::from(0);
+    }
+
+    emitted_rewards(last_update_time)
+        .checked_mul(alloc_point)
+        .and_then(|reward| reward.checked_mul(U256::from(REWARD_PRECISION)))
+        .ok_or(StakingError::Overflow).unwrap_or_revert()
+        / total_alloc_point
+}
+
+/// Computes the running sum of 'R' of the pool over its total supply up to the current block time
+/// and the remainder of the division, 'R' is the pool's share of "reward_rate", without writing them
+fn reward_per_token_at(pool_id: u32) -> (U256, U256) {
+
+    let reward_per_token_stored: U256 = pool_read(POOL_REWARD_PER_TOKEN_STORED_KEY_NAME, pool_id).unwrap_or_default();
+    let reward_per_token_dust: U256 = pool_read(POOL_REWARD_PER_TOKEN_DUST_KEY_NAME, pool_id).unwrap_or_default();
+    let total_supply: U256 = pool_read(POOL_TOTAL_SUPPLIES_KEY_NAME, pool_id).unwrap_or_default();
+
+    if total_supply.is_zero() {
+        return (reward_per_token_stored, reward_per_token_dust);
+    }
+
+    // plus whatever could not be distributed last time
+    let scaled_reward: U256 = pool_scaled_rewards(pool_id)
+        .checked_add(reward_per_token_dust)
+        .ok_or(StakingError::Overflow).unwrap_or_revert();
+
+    let reward_per_token: U256 = reward_per_token_stored
+        .checked_add(scaled_reward / total_supply)
+        .ok_or(StakingError::Overflow).unwrap_or_revert();
+
+    // remainder of the division is carried over to the next update instead of being lost
+    (reward_per_token, scaled_reward % total_supply)
+}
+
+/// Amount of Reward Tokens `staker` accrued in `pool_id` since the last checkpoint
+fn accrued_reward(staker: Address, pool_id: u32, reward_per_token: U256) -> U256 {
+
+    let balance: U256 = user_read(BALANCES_KEY_NAME, staker, pool_id);
+    let user_reward_per_token_paid: U256 = user_read(USER_REWARD_PER_TOKEN_PAID_KEY_NAME, staker, pool_id);
+
+    // reward per token is scaled by REWARD_PRECISION
+    balance * (reward_per_token - user_reward_per_token_paid) / U256::from(REWARD_PRECISION)
+}
+
+fn dictionary_uref(dictionary_name: &str) -> URef {
+    let dictionary_key: Key = runtime::get_key(dictionary_name).unwrap_or_revert();
+
+    dictionary_key.into_uref().unwrap_or_revert()
+}
+
+/// Reads the item of `pool_id` from the pool dictionary `dictionary_name`
+fn pool_read<T: FromBytes + CLTyped>(dictionary_name: &str, pool_id: u32) -> Option<T> {
+    dictionary_get(dictionary_uref(dictionary_name), &pool_id.to_string())
+}
+
+fn pool_write<T: ToBytes + CLTyped>(dictionary_name: &str, pool_id: u32, value: T) {
+    storage::dictionary_put(dictionary_uref(dictionary_name), &pool_id.to_string(), value);
+}
+
+fn pool_add(dictionary_name: &str, pool_id: u32, amount: U256) {
+    let value: U256 = pool_read(dictionary_name, pool_id).unwrap_or_default();
+
+    pool_write(
+        dictionary_name,
+        pool_id,
+        value.checked_add(amount).ok_or(StakingError::Overflow).unwrap_or_revert()
+    );
+}
+
+fn pool_sub(dictionary_name: &str, pool_id: u32, amount: U256) {
+    let value: U256 = pool_read(dictionary_name, pool_id).unwrap_or_default();
+
+    pool_write(
+        dictionary_name,
+        pool_id,
+        value.checked_sub(amount).ok_or(StakingError::InsufficientStake).unwrap_or_revert()
+    );
+}
+
+/// Reads the item of `address` in `pool_id` from the user dictionary `dictionary_name`
+fn user_read(dictionary_name: &str, address: Address, pool_id: u32) -> U256 {
+    dictionary_get(dictionary_uref(dictionary_name), &make_indexed_item_key(address, pool_id)).unwrap_or_default()
+}
+
+fn user_write(dictionary_name: &str, address: Address, pool_id: u32, value: U256) {
+    storage::dictionary_put(dictionary_uref(dictionary_name), &make_indexed_item_key(address, pool_id), value);
+}
+
+fn user_add(dictionary_name: &str, address: Address, pool_id: u32, amount: U256) {
+    if amount.is_zero() {
+        return;
+    }
+
+    let value: U256 = user_read(dictionary_name, address, pool_id);
+
+    user_write(
+        dictionary_name,
+        address,
+        pool_id,
+        value.checked_add(amount).ok_or(StakingError::Overflow).unwrap_or_revert()
+    );
+}
+
+fn user_sub(dictionary_name: &str, address: Address, pool_id: u32, amount: U256) {
+    let value: U256 = user_read(dictionary_name, address, pool_id);
+
+    user_write(
+        dictionary_name,
+        address,
+        pool_id,
+        value.checked_sub(amount).ok_or(StakingError::InsufficientStake).unwrap_or_revert()
+    );
+}
+
+fn named_key_add(amount: U256, key_name: &str) {
+    let current_value: U256 = get_key(key_name).unwrap_or_revert();
+
+    set_key(key_name, current_value.checked_add(amount).ok_or(StakingError::Overflow).unwrap_or_revert());
+}
+
+fn named_key_sub(amount: U256, key_name: &str) {
+    let current_value: U256 = get_key(key_name).unwrap_or_revert();
+
+    set_key(key_name, current_value.checked_sub(amount).ok_or(StakingError::InsufficientStake).unwrap_or_revert());
+}
+
+fn erc20_contract_hash(erc20_token: Key) -> ContractHash {
+    ContractHash::new(erc20_token.into_hash().unwrap_or_revert())
+}
+
+fn erc20_transfer_from(erc20_token: Key, owner: Address, amount: U256) {
+    let erc20_contract_hash: ContractHash = erc20_contract_hash(erc20_token);
+
+    let self_addr: Address = get_self_address().unwrap_or_revert();
+
+    // Caller has to 'approve' the chef contract first
+    let allowance: U256 = runtime::call_contract(erc20_contract_hash, ALLOWANCE_ENTRY_POINT_NAME, runtime_args!{
+        OWNER_RUNTIME_ARG_NAME => owner,
+        SPENDER_RUNTIME_ARG_NAME => self_addr
+    });
+
+    if allowance < amount {
+        runtime::revert(StakingError::InsufficientAllowance);
+    }
+
+    let _: () = runtime::call_contract(erc20_contract_hash, TRANSFER_FROM_ENTRY_POINT_NAME, runtime_args!{
+        OWNER_RUNTIME_ARG_NAME => owner,
+        RECIPIENT_RUNTIME_ARG_NAME => self_addr,
+        AMOUNT_RUNTIME_ARG_NAME => amount
+    });
+}
+
+fn erc20_transfer(erc20_token: Key, recipient: Address, amount: U256) {
+    runtime::call_contract(erc20_contract_hash(erc20_token), TRANSFER_ENTRY_POINT_NAME, runtime_args!{
+        RECIPIENT_RUNTIME_ARG_NAME => recipient,
+        AMOUNT_RUNTIME_ARG_NAME => amount
+    })
+}
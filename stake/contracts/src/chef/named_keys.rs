@@ -0,0 +1,163 @@
+use casper_types::{U256, URef, contracts::NamedKeys, Key};
+use casper_erc20::Address;
+use alloc::string::{String, ToString};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert
+};
+
+use crate::constants::{
+    CHEF_CONTRACT_KEY_NAME, REWARD_TOKEN_HASH_KEY_NAME, REWARD_RATE_KEY_NAME, PERIOD_FINISH_KEY_NAME,
+    LAST_UPDATE_KEY_NAME, REWARD_RESERVE_KEY_NAME, OUTSTANDING_REWARDS_KEY_NAME,
+    TOTAL_ALLOC_POINT_KEY_NAME, POOL_COUNT_KEY_NAME, OWNER_KEY_NAME, PENDING_OWNER_KEY_NAME,
+    POOL_STAKE_TOKENS_KEY_NAME, POOL_ALLOC_POINTS_KEY_NAME, POOL_TOTAL_SUPPLIES_KEY_NAME,
+    POOL_LAST_UPDATE_KEY_NAME, POOL_REWARD_PER_TOKEN_STORED_KEY_NAME,
+    POOL_REWARD_PER_TOKEN_DUST_KEY_NAME, BALANCES_KEY_NAME, REWARDS_KEY_NAME,
+    USER_REWARD_PER_TOKEN_PAID_KEY_NAME
+};
+
+pub fn default(
+    chef_contract_name: String,
+    reward_token_hash_key: Key,
+    owner: Address
+            ) -> NamedKeys {
+    
+    let mut named_keys = NamedKeys::new();
+
+    // Contract 'Named keys'
+
+    // 0. Name of the Chef contract
+    let chef_contract_name_key = {
+        let chef_contract_name_uref = storage::new_uref(chef_contract_name).into_read();
+        Key::from(chef_contract_name_uref)
+    };
+
+    // 1. 'Contract Hash' of ERC20 'Reward'(PICAS) token paid by every pool, read only
+    let reward_token_key = {
+        let reward_token_uref: URef = storage::new_uref(reward_token_hash_key).into_read();
+        Key::from(reward_token_uref)
+    };
+
+    // 2. "reward_rate", read and write
+    // Reward Tokens emitted per time unit during the reward period, split across pools by their allocation points
+    let reward_rate: U256 = U256::from(0);
+    let reward_rate_key = {
+        let reward_rate_uref = storage::new_uref(reward_rate).into_read_write();
+        Key::from(reward_rate_uref)
+    };
+
+    // 3. "period_finish", read and write
+    // Time the reward period started by 'notify_reward_amount' ends at
+    let period_finish: U256 = U256::from(0);
+    let period_finish_key = {
+        let period_finish_uref = storage::new_uref(period_finish).into_read_write();
+        Key::from(period_finish_uref)
+    };
+
+    // 4. "last_update_time", read and write
+    // Time all the pools were last updated at together
+    let last_update_time: U256 = U256::from(0);
+    let last_update_time_key = {
+        let last_update_time_uref = storage::new_uref(last_update_time).into_read_write();
+        Key::from(last_update_time_uref)
+    };
+
+    // 5. "reward_reserve", read and write
+    // Reward Tokens funded for rewards, Reward Tokens staked in pools are never part of it
+    let reward_reserve: U256 = U256::from(0);
+    let reward_reserve_key = {
+        let reward_reserve_uref = storage::new_uref(reward_reserve).into_read_write();
+        Key::from(reward_reserve_uref)
+    };
+
+    // 6. "outstanding_rewards", read and write
+    // Rewards owed to stakers or promised by the running period, the reserve always covers them
+    let outstanding_rewards: U256 = U256::from(0);
+    let outstanding_rewards_key = {
+        let outstanding_rewards_uref = storage::new_uref(outstanding_rewards).into_read_write();
+        Key::from(outstanding_rewards_uref)
+    };
+
+    // 7. "total_alloc_point", read and write
+    // Sum of allocation points of all pools
+    let total_alloc_point: U256 = U256::from(0);
+    let total_alloc_point_key = {
+        let total_alloc_point_uref = storage::new_uref(total_alloc_point).into_read_write();
+        Key::from(total_alloc_point_uref)
+    };
+
+    // 8. "pool_count", read and write
+    // Number of pools, id of the next pool
+    let pool_count: u32 = 0;
+    let pool_count_key = {
+        let pool_count_uref = storage::new_uref(pool_count).into_read_write();
+        Key::from(pool_count_uref)
+    };
+
+    // 9. "owner", read and write
+    // Account or contract allowed to add pools, change allocation points and start reward periods
+    let owner_key = {
+        let owner_uref = storage::new_uref(owner).into_read_write();
+        Key::from(owner_uref)
+    };
+
+    // 10. "pending_owner", read and write
+    // Set by 'transfer_ownership' until the new owner accepts it
+    let pending_owner: Option<Address> = None;
+    let pending_owner_key = {
+        let pending_owner_uref = storage::new_uref(pending_owner).into_read_write();
+        Key::from(pending_owner_uref)
+    };
+
+    named_keys.insert(CHEF_CONTRACT_KEY_NAME.to_string(), chef_contract_name_key);
+    named_keys.insert(REWARD_TOKEN_HASH_KEY_NAME.to_string(), reward_token_key);
+    named_keys.insert(REWARD_RATE_KEY_NAME.to_string(), reward_rate_key);
+    named_keys.insert(PERIOD_FINISH_KEY_NAME.to_string(), period_finish_key);
+    named_keys.insert(LAST_UPDATE_KEY_NAME.to_string(), last_update_time_key);
+    named_keys.insert(REWARD_RESERVE_KEY_NAME.to_string(), reward_reserve_key);
+    named_keys.insert(OUTSTANDING_REWARDS_KEY_NAME.to_string(), outstanding_rewards_key);
+    named_keys.insert(TOTAL_ALLOC_POINT_KEY_NAME.to_string(), total_alloc_point_key);
+    named_keys.insert(POOL_COUNT_KEY_NAME.to_string(), pool_count_key);
+    named_keys.insert(OWNER_KEY_NAME.to_string(), owner_key);
+    named_keys.insert(PENDING_OWNER_KEY_NAME.to_string(), pending_owner_key);
+
+    // Contract 'Dictionaries'
+
+    // Pools, keyed by pool id
+    for dictionary_name in [
+        // 'Contract Hash' of the ERC20 Stake Token of the pool
+        POOL_STAKE_TOKENS_KEY_NAME,
+        // Share of "reward_rate" of the pool
+        POOL_ALLOC_POINTS_KEY_NAME,
+        // Stake Tokens staked in the pool
+        POOL_TOTAL_SUPPLIES_KEY_NAME,
+        // Time the running sum of the pool was last updated at
+        POOL_LAST_UPDATE_KEY_NAME,
+        // Running sum p = sum(t,0,a-1){ R / L(t)} of the pool, scaled by REWARD_PRECISION
+        POOL_REWARD_PER_TOKEN_STORED_KEY_NAME,
+        // Remainder of the last division of the running sum, carried to the next update
+        POOL_REWARD_PER_TOKEN_DUST_KEY_NAME,
+    ] {
+        let dictionary_uref: URef = storage::new_dictionary(dictionary_name).unwrap_or_revert();
+        named_keys.insert(dictionary_name.to_string(), Key::from(dictionary_uref));
+        // only the contract keeps it, so the installer can install more contracts
+        runtime::remove_key(dictionary_name);
+    }
+
+    // Users, keyed by address and pool id
+    for dictionary_name in [
+        // Stake of users in a pool
+        BALANCES_KEY_NAME,
+        // Rewards credited to users in a pool, not paid yet
+        REWARDS_KEY_NAME,
+        // Running sum of the pool at the last update of the user
+        USER_REWARD_PER_TOKEN_PAID_KEY_NAME,
+    ] {
+        let dictionary_uref: URef = storage::new_dictionary(dictionary_name).unwrap_or_revert();
+        named_keys.insert(dictionary_name.to_string(), Key::from(dictionary_uref));
+        // only the contract keeps it, so the installer can install more contracts
+        runtime::remove_key(dictionary_name);
+    }
+    
+    named_keys
+}
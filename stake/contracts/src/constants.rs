@@ -2,6 +2,33 @@
 
 pub const STAKING_CONTRACT_KEY_NAME: &str = "staking_contract";
 
+/// Fixed-point scale of 'reward_per_token_stored' and 'user_reward_per_token_paid'
+pub const REWARD_PRECISION: u64 = 1_000_000_000_000_000_000;
+
+/// Lock multipliers are in basis points, 10_000 is 1x
+pub const MULTIPLIER_BASE: u64 = 10_000;
+
+/// Early-exit penalty rate is in basis points, 10_000 is the whole withdrawn amount
+pub const PENALTY_RATE_BASE: u64 = 10_000;
+
+/// Share of the effective balance earning rewards without any boost, in basis points.
+/// A full boost lifts the working balance to the whole effective balance, 2.5x this share
+pub const UNBOOSTED_SHARE: u64 = 4_000;
+
+/// Working balance shares are in basis points, 10_000 is the whole effective balance
+pub const BOOST_SHARE_BASE: u64 = 10_000;
+
+/// Every reward token is checkpointed on each stake and withdraw, so their number is bounded
+pub const MAX_REWARD_TOKENS: usize = 8;
+
+/// Emission schedules are integrated on each checkpoint too, so their segments are bounded.
+/// Two years of halvings every 90 days take 10 segments
+pub const MAX_EMISSION_SEGMENTS: usize = 32;
+
+/// Version of the contract schema, bumped by every upgrade that changes the stored state.
+/// 'migrate' brings the state of older versions up to it
+pub const CONTRACT_VERSION: u32 = 1;
+
 // Named keys
 
 pub const STAKE_TOKEN_HASH_KEY_NAME: &str = "stake_token_hash";
@@ -14,8 +41,70 @@ pub const LAST_UPDATE_KEY_NAME: &str = "last_update_time";
 
 pub const REWARD_PER_TOKEN_STORED_KEY_NAME: &str = "reward_per_token_stored";
 
+pub const REWARD_PER_TOKEN_DUST_KEY_NAME: &str = "reward_per_token_dust";
+
 pub const TOTAL_SUPPLY_KEY_NAME: &str = "total_supply";
 
+pub const PERIOD_FINISH_KEY_NAME: &str = "period_finish";
+
+pub const OWNER_KEY_NAME: &str = "owner";
+
+pub const PENDING_OWNER_KEY_NAME: &str = "pending_owner";
+
+pub const PAUSED_KEY_NAME: &str = "paused";
+
+pub const EVENT_COUNT_KEY_NAME: &str = "event_count";
+
+pub const EFFECTIVE_SUPPLY_KEY_NAME: &str = "effective_supply";
+
+pub const LOCK_TIERS_KEY_NAME: &str = "lock_tiers";
+
+pub const REWARD_TOKENS_KEY_NAME: &str = "reward_tokens";
+
+pub const COMPOUNDING_ENABLED_KEY_NAME: &str = "compounding_enabled";
+
+pub const CONTRACT_VERSION_KEY_NAME: &str = "contract_version";
+
+pub const STAKE_TOKEN_DECIMALS_KEY_NAME: &str = "stake_token_decimals";
+
+pub const REWARD_TOKEN_DECIMALS_KEY_NAME: &str = "reward_token_decimals";
+
+pub const REWARD_RESERVE_KEY_NAME: &str = "reward_reserve";
+
+pub const OUTSTANDING_REWARDS_KEY_NAME: &str = "outstanding_rewards";
+
+pub const UNBONDING_PERIOD_KEY_NAME: &str = "unbonding_period";
+
+pub const PENALTY_PERIOD_KEY_NAME: &str = "penalty_period";
+
+pub const PENALTY_RATE_KEY_NAME: &str = "penalty_rate";
+
+pub const TREASURY_KEY_NAME: &str = "treasury";
+
+pub const VESTING_PERIOD_KEY_NAME: &str = "vesting_period";
+
+pub const BOOST_TOKEN_HASH_KEY_NAME: &str = "boost_token_hash";
+
+pub const BOOST_SUPPLY_KEY_NAME: &str = "boost_supply";
+
+pub const WORKING_SUPPLY_KEY_NAME: &str = "working_supply";
+
+pub const TOTAL_SUPPLY_CHECKPOINT_COUNT_KEY_NAME: &str = "total_supply_checkpoint_count";
+
+pub const ALLOWLIST_ENABLED_KEY_NAME: &str = "allowlist_enabled";
+
+pub const MIN_STAKE_KEY_NAME: &str = "min_stake";
+
+pub const MAX_STAKE_PER_ADDRESS_KEY_NAME: &str = "max_stake_per_address";
+
+pub const POOL_CAP_KEY_NAME: &str = "pool_cap";
+
+pub const EMISSION_SCHEDULE_KEY_NAME: &str = "emission_schedule";
+
+pub const TOTAL_UNBONDING_KEY_NAME: &str = "total_unbonding";
+
+pub const ESCROWED_REWARDS_KEY_NAME: &str = "escrowed_rewards";
+
 // Dictionaries
 
 pub const BALANCES_KEY_NAME: &str = "balances";
@@ -24,6 +113,36 @@ pub const REWARDS_KEY_NAME: &str = "rewards";
 
 pub const USER_REWARD_PER_TOKEN_PAID_KEY_NAME: &str = "user_reward_per_token_paid";
 
+pub const EVENTS_KEY_NAME: &str = "events";
+
+pub const EFFECTIVE_BALANCES_KEY_NAME: &str = "effective_balances";
+
+pub const LOCKED_BALANCES_KEY_NAME: &str = "locked_balances";
+
+pub const LOCK_POSITIONS_KEY_NAME: &str = "lock_positions";
+
+pub const LOCK_POSITION_COUNTS_KEY_NAME: &str = "lock_position_counts";
+
+pub const UNBONDING_ENTRIES_KEY_NAME: &str = "unbonding_entries";
+
+pub const WEIGHTED_STAKE_TIMES_KEY_NAME: &str = "weighted_stake_times";
+
+pub const VESTING_ESCROWS_KEY_NAME: &str = "vesting_escrows";
+
+pub const VESTED_REWARDS_KEY_NAME: &str = "vested_rewards";
+
+pub const BOOST_BALANCES_KEY_NAME: &str = "boost_balances";
+
+pub const WORKING_BALANCES_KEY_NAME: &str = "working_balances";
+
+pub const BALANCE_CHECKPOINTS_KEY_NAME: &str = "balance_checkpoints";
+
+pub const BALANCE_CHECKPOINT_COUNTS_KEY_NAME: &str = "balance_checkpoint_counts";
+
+pub const TOTAL_SUPPLY_CHECKPOINTS_KEY_NAME: &str = "total_supply_checkpoints";
+
+pub const ALLOWLIST_KEY_NAME: &str = "allowlist";
+
 // Entry points
 
 pub const STAKE_ENTRY_POINT_NAME: &str = "stake";
@@ -32,6 +151,150 @@ pub const WITHDRAW_ENTRY_POINT_NAME: &str = "withdraw";
 
 pub const GET_REWARD_ENTRY_POINT_NAME: &str = "get_reward";
 
+pub const NOTIFY_REWARD_AMOUNT_ENTRY_POINT_NAME: &str = "notify_reward_amount";
+
+pub const TRANSFER_OWNERSHIP_ENTRY_POINT_NAME: &str = "transfer_ownership";
+
+pub const ACCEPT_OWNERSHIP_ENTRY_POINT_NAME: &str = "accept_ownership";
+
+pub const SET_REWARD_RATE_ENTRY_POINT_NAME: &str = "set_reward_rate";
+
+pub const SET_STAKE_TOKEN_ENTRY_POINT_NAME: &str = "set_stake_token";
+
+pub const SET_REWARD_TOKEN_ENTRY_POINT_NAME: &str = "set_reward_token";
+
+pub const PAUSE_ENTRY_POINT_NAME: &str = "pause";
+
+pub const UNPAUSE_ENTRY_POINT_NAME: &str = "unpause";
+
+pub const EMERGENCY_WITHDRAW_ENTRY_POINT_NAME: &str = "emergency_withdraw";
+
+pub const STAKE_LOCKED_ENTRY_POINT_NAME: &str = "stake_locked";
+
+pub const UNLOCK_POSITION_ENTRY_POINT_NAME: &str = "unlock_position";
+
+pub const SET_LOCK_TIERS_ENTRY_POINT_NAME: &str = "set_lock_tiers";
+
+pub const ADD_REWARD_TOKEN_ENTRY_POINT_NAME: &str = "add_reward_token";
+
+pub const GET_REWARD_FOR_ENTRY_POINT_NAME: &str = "get_reward_for";
+
+pub const COMPOUND_ENTRY_POINT_NAME: &str = "compound";
+
+pub const MIGRATE_ENTRY_POINT_NAME: &str = "migrate";
+
+pub const FUND_REWARDS_ENTRY_POINT_NAME: &str = "fund_rewards";
+
+pub const REQUEST_WITHDRAW_ENTRY_POINT_NAME: &str = "request_withdraw";
+
+pub const CLAIM_UNBONDED_ENTRY_POINT_NAME: &str = "claim_unbonded";
+
+pub const CLAIM_VESTED_ENTRY_POINT_NAME: &str = "claim_vested";
+
+pub const SET_BOOST_TOKEN_ENTRY_POINT_NAME: &str = "set_boost_token";
+
+pub const LOCK_BOOST_ENTRY_POINT_NAME: &str = "lock_boost";
+
+pub const UNLOCK_BOOST_ENTRY_POINT_NAME: &str = "unlock_boost";
+
+pub const STAKE_FOR_ENTRY_POINT_NAME: &str = "stake_for";
+
+pub const WITHDRAW_TO_ENTRY_POINT_NAME: &str = "withdraw_to";
+
+pub const GET_REWARD_TO_ENTRY_POINT_NAME: &str = "get_reward_to";
+
+pub const ADD_TO_ALLOWLIST_ENTRY_POINT_NAME: &str = "add_to_allowlist";
+
+pub const REMOVE_FROM_ALLOWLIST_ENTRY_POINT_NAME: &str = "remove_from_allowlist";
+
+pub const SET_ALLOWLIST_ENABLED_ENTRY_POINT_NAME: &str = "set_allowlist_enabled";
+
+pub const SET_STAKE_LIMITS_ENTRY_POINT_NAME: &str = "set_stake_limits";
+
+pub const SET_EMISSION_SCHEDULE_ENTRY_POINT_NAME: &str = "set_emission_schedule";
+
+pub const RECOVER_TOKENS_ENTRY_POINT_NAME: &str = "recover_tokens";
+
+// Views
+
+pub const BALANCE_OF_ENTRY_POINT_NAME: &str = "balance_of";
+
+pub const EARNED_ENTRY_POINT_NAME: &str = "earned";
+
+pub const REWARD_PER_TOKEN_ENTRY_POINT_NAME: &str = "reward_per_token";
+
+pub const TOTAL_SUPPLY_ENTRY_POINT_NAME: &str = "total_supply";
+
+pub const REWARD_RATE_ENTRY_POINT_NAME: &str = "reward_rate";
+
+pub const LAST_TIME_REWARD_APPLICABLE_ENTRY_POINT_NAME: &str = "last_time_reward_applicable";
+
+pub const EFFECTIVE_BALANCE_OF_ENTRY_POINT_NAME: &str = "effective_balance_of";
+
+pub const LOCKED_BALANCE_OF_ENTRY_POINT_NAME: &str = "locked_balance_of";
+
+pub const REWARD_TOKENS_ENTRY_POINT_NAME: &str = "reward_tokens";
+
+pub const REWARD_RESERVE_ENTRY_POINT_NAME: &str = "reward_reserve";
+
+pub const OUTSTANDING_REWARDS_ENTRY_POINT_NAME: &str = "outstanding_rewards";
+
+pub const UNBONDING_ENTRIES_ENTRY_POINT_NAME: &str = "unbonding_entries";
+
+pub const PENALTY_SCHEDULE_ENTRY_POINT_NAME: &str = "penalty_schedule";
+
+pub const TREASURY_ENTRY_POINT_NAME: &str = "treasury";
+
+pub const WEIGHTED_STAKE_TIME_ENTRY_POINT_NAME: &str = "weighted_stake_time";
+
+pub const LOCKED_REWARDS_OF_ENTRY_POINT_NAME: &str = "locked_rewards_of";
+
+pub const UNLOCKED_REWARDS_OF_ENTRY_POINT_NAME: &str = "unlocked_rewards_of";
+
+pub const BOOST_TOKEN_ENTRY_POINT_NAME: &str = "boost_token";
+
+pub const BOOST_BALANCE_OF_ENTRY_POINT_NAME: &str = "boost_balance_of";
+
+pub const WORKING_BALANCE_OF_ENTRY_POINT_NAME: &str = "working_balance_of";
+
+pub const WORKING_SUPPLY_ENTRY_POINT_NAME: &str = "working_supply";
+
+pub const BALANCE_OF_AT_ENTRY_POINT_NAME: &str = "balance_of_at";
+
+pub const TOTAL_SUPPLY_AT_ENTRY_POINT_NAME: &str = "total_supply_at";
+
+pub const OWNER_ENTRY_POINT_NAME: &str = "owner";
+
+pub const IS_ALLOWLISTED_ENTRY_POINT_NAME: &str = "is_allowlisted";
+
+pub const STAKE_LIMITS_ENTRY_POINT_NAME: &str = "stake_limits";
+
+pub const REMAINING_CAPACITY_ENTRY_POINT_NAME: &str = "remaining_capacity";
+
+pub const EMISSION_RATES_ENTRY_POINT_NAME: &str = "emission_rates";
+
 // Runtime argument names
 
-pub const AMOUNT_KEY_NAME:  &str = "amount";
\ No newline at end of file
+pub const AMOUNT_KEY_NAME:  &str = "amount";
+
+pub const DURATION_KEY_NAME: &str = "duration";
+
+pub const NEW_OWNER_KEY_NAME: &str = "new_owner";
+
+pub const ADDRESS_KEY_NAME: &str = "address";
+
+pub const LOCK_DURATION_KEY_NAME: &str = "lock_duration";
+
+pub const POSITION_ID_KEY_NAME: &str = "position_id";
+
+pub const REWARD_TOKEN_KEY_NAME: &str = "reward_token";
+
+pub const TOKEN_KEY_NAME: &str = "token";
+
+pub const BENEFICIARY_KEY_NAME: &str = "beneficiary";
+
+pub const RECIPIENT_KEY_NAME: &str = "recipient";
+
+pub const TIMESTAMP_KEY_NAME: &str = "timestamp";
+
+pub const ADDRESSES_KEY_NAME: &str = "addresses";
\ No newline at end of file
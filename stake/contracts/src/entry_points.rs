@@ -1,12 +1,43 @@
-use alloc::{string::String, vec};
+use alloc::{string::String, vec, vec::Vec};
 
 use crate::constants::{
     GET_REWARD_ENTRY_POINT_NAME, WITHDRAW_ENTRY_POINT_NAME,
-    STAKE_ENTRY_POINT_NAME, AMOUNT_KEY_NAME
+    STAKE_ENTRY_POINT_NAME, NOTIFY_REWARD_AMOUNT_ENTRY_POINT_NAME,
+    TRANSFER_OWNERSHIP_ENTRY_POINT_NAME, ACCEPT_OWNERSHIP_ENTRY_POINT_NAME,
+    SET_REWARD_RATE_ENTRY_POINT_NAME, SET_STAKE_TOKEN_ENTRY_POINT_NAME,
+    SET_REWARD_TOKEN_ENTRY_POINT_NAME, AMOUNT_KEY_NAME, DURATION_KEY_NAME,
+    NEW_OWNER_KEY_NAME, REWARD_RATE_KEY_NAME, STAKE_TOKEN_HASH_KEY_NAME,
+    REWARD_TOKEN_HASH_KEY_NAME, PAUSE_ENTRY_POINT_NAME, UNPAUSE_ENTRY_POINT_NAME,
+    EMERGENCY_WITHDRAW_ENTRY_POINT_NAME, BALANCE_OF_ENTRY_POINT_NAME, EARNED_ENTRY_POINT_NAME,
+    REWARD_PER_TOKEN_ENTRY_POINT_NAME, TOTAL_SUPPLY_ENTRY_POINT_NAME, REWARD_RATE_ENTRY_POINT_NAME,
+    LAST_TIME_REWARD_APPLICABLE_ENTRY_POINT_NAME, ADDRESS_KEY_NAME, STAKE_LOCKED_ENTRY_POINT_NAME,
+    UNLOCK_POSITION_ENTRY_POINT_NAME, SET_LOCK_TIERS_ENTRY_POINT_NAME,
+    EFFECTIVE_BALANCE_OF_ENTRY_POINT_NAME, LOCKED_BALANCE_OF_ENTRY_POINT_NAME,
+    LOCK_DURATION_KEY_NAME, POSITION_ID_KEY_NAME, LOCK_TIERS_KEY_NAME,
+    ADD_REWARD_TOKEN_ENTRY_POINT_NAME, GET_REWARD_FOR_ENTRY_POINT_NAME,
+    REWARD_TOKENS_ENTRY_POINT_NAME, REWARD_TOKEN_KEY_NAME, COMPOUND_ENTRY_POINT_NAME,
+    STAKE_FOR_ENTRY_POINT_NAME, WITHDRAW_TO_ENTRY_POINT_NAME, GET_REWARD_TO_ENTRY_POINT_NAME,
+    BENEFICIARY_KEY_NAME, RECIPIENT_KEY_NAME, MIGRATE_ENTRY_POINT_NAME, FUND_REWARDS_ENTRY_POINT_NAME,
+    REWARD_RESERVE_ENTRY_POINT_NAME, OUTSTANDING_REWARDS_ENTRY_POINT_NAME,
+    REQUEST_WITHDRAW_ENTRY_POINT_NAME, CLAIM_UNBONDED_ENTRY_POINT_NAME, UNBONDING_ENTRIES_ENTRY_POINT_NAME,
+    PENALTY_SCHEDULE_ENTRY_POINT_NAME, TREASURY_ENTRY_POINT_NAME, WEIGHTED_STAKE_TIME_ENTRY_POINT_NAME,
+    CLAIM_VESTED_ENTRY_POINT_NAME, LOCKED_REWARDS_OF_ENTRY_POINT_NAME, UNLOCKED_REWARDS_OF_ENTRY_POINT_NAME,
+    SET_BOOST_TOKEN_ENTRY_POINT_NAME, LOCK_BOOST_ENTRY_POINT_NAME, UNLOCK_BOOST_ENTRY_POINT_NAME,
+    BOOST_TOKEN_HASH_KEY_NAME, BOOST_TOKEN_ENTRY_POINT_NAME, BOOST_BALANCE_OF_ENTRY_POINT_NAME,
+    WORKING_BALANCE_OF_ENTRY_POINT_NAME, WORKING_SUPPLY_ENTRY_POINT_NAME, BALANCE_OF_AT_ENTRY_POINT_NAME,
+    TOTAL_SUPPLY_AT_ENTRY_POINT_NAME, TIMESTAMP_KEY_NAME, OWNER_ENTRY_POINT_NAME,
+    ADD_TO_ALLOWLIST_ENTRY_POINT_NAME, REMOVE_FROM_ALLOWLIST_ENTRY_POINT_NAME,
+    SET_ALLOWLIST_ENABLED_ENTRY_POINT_NAME, IS_ALLOWLISTED_ENTRY_POINT_NAME, ADDRESSES_KEY_NAME,
+    ALLOWLIST_ENABLED_KEY_NAME, SET_STAKE_LIMITS_ENTRY_POINT_NAME, STAKE_LIMITS_ENTRY_POINT_NAME,
+    REMAINING_CAPACITY_ENTRY_POINT_NAME, MIN_STAKE_KEY_NAME, MAX_STAKE_PER_ADDRESS_KEY_NAME, POOL_CAP_KEY_NAME,
+    SET_EMISSION_SCHEDULE_ENTRY_POINT_NAME, EMISSION_RATES_ENTRY_POINT_NAME, EMISSION_SCHEDULE_KEY_NAME,
+    RECOVER_TOKENS_ENTRY_POINT_NAME, TOKEN_KEY_NAME
     };
 
+use casper_erc20::Address;
+
 use casper_types::{
-    U256, CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Parameter
+    U256, Key, CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Parameter
     };
 
 fn stake() -> EntryPoint {
@@ -43,6 +74,711 @@ fn get_reward() -> EntryPoint {
     )
 }
 
+fn stake_for() -> EntryPoint {
+    EntryPoint::new(
+        String::from(STAKE_FOR_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(BENEFICIARY_KEY_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_KEY_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn withdraw_to() -> EntryPoint {
+    EntryPoint::new(
+        String::from(WITHDRAW_TO_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT_KEY_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_KEY_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn get_reward_to() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_REWARD_TO_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT_KEY_NAME, Address::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn get_reward_for() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_REWARD_FOR_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(REWARD_TOKEN_KEY_NAME, Key::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn compound() -> EntryPoint {
+    EntryPoint::new(
+        String::from(COMPOUND_ENTRY_POINT_NAME),
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn migrate() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MIGRATE_ENTRY_POINT_NAME),
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn request_withdraw() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REQUEST_WITHDRAW_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(AMOUNT_KEY_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn claim_unbonded() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CLAIM_UNBONDED_ENTRY_POINT_NAME),
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn claim_vested() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CLAIM_VESTED_ENTRY_POINT_NAME),
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn emergency_withdraw() -> EntryPoint {
+    EntryPoint::new(
+        String::from(EMERGENCY_WITHDRAW_ENTRY_POINT_NAME),
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn stake_locked() -> EntryPoint {
+    EntryPoint::new(
+        String::from(STAKE_LOCKED_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(AMOUNT_KEY_NAME, U256::cl_type()),
+            Parameter::new(LOCK_DURATION_KEY_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn unlock_position() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UNLOCK_POSITION_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS_KEY_NAME, Address::cl_type()),
+            Parameter::new(POSITION_ID_KEY_NAME, u32::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn set_lock_tiers() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_LOCK_TIERS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(LOCK_TIERS_KEY_NAME, Vec::<(U256, U256)>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn add_to_allowlist() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ADD_TO_ALLOWLIST_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESSES_KEY_NAME, Vec::<Address>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn remove_from_allowlist() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REMOVE_FROM_ALLOWLIST_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESSES_KEY_NAME, Vec::<Address>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn set_allowlist_enabled() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_ALLOWLIST_ENABLED_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ALLOWLIST_ENABLED_KEY_NAME, bool::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn set_stake_limits() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_STAKE_LIMITS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(MIN_STAKE_KEY_NAME, U256::cl_type()),
+            Parameter::new(MAX_STAKE_PER_ADDRESS_KEY_NAME, U256::cl_type()),
+            Parameter::new(POOL_CAP_KEY_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn notify_reward_amount() -> EntryPoint {
+    EntryPoint::new(
+        String::from(NOTIFY_REWARD_AMOUNT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(REWARD_TOKEN_KEY_NAME, Key::cl_type()),
+            Parameter::new(AMOUNT_KEY_NAME, U256::cl_type()),
+            Parameter::new(DURATION_KEY_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn fund_rewards() -> EntryPoint {
+    EntryPoint::new(
+        String::from(FUND_REWARDS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(REWARD_TOKEN_KEY_NAME, Key::cl_type()),
+            Parameter::new(AMOUNT_KEY_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn recover_tokens() -> EntryPoint {
+    EntryPoint::new(
+        String::from(RECOVER_TOKENS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_KEY_NAME, Key::cl_type()),
+            Parameter::new(AMOUNT_KEY_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn transfer_ownership() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TRANSFER_OWNERSHIP_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(NEW_OWNER_KEY_NAME, Address::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn accept_ownership() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ACCEPT_OWNERSHIP_ENTRY_POINT_NAME),
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn set_reward_rate() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_REWARD_RATE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(REWARD_TOKEN_KEY_NAME, Key::cl_type()),
+            Parameter::new(REWARD_RATE_KEY_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn set_emission_schedule() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_EMISSION_SCHEDULE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(REWARD_TOKEN_KEY_NAME, Key::cl_type()),
+            Parameter::new(EMISSION_SCHEDULE_KEY_NAME, Vec::<(U256, U256)>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn set_stake_token() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_STAKE_TOKEN_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(STAKE_TOKEN_HASH_KEY_NAME, Key::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn set_boost_token() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_BOOST_TOKEN_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(BOOST_TOKEN_HASH_KEY_NAME, Key::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn lock_boost() -> EntryPoint {
+    EntryPoint::new(
+        String::from(LOCK_BOOST_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(AMOUNT_KEY_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn unlock_boost() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UNLOCK_BOOST_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(AMOUNT_KEY_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn set_reward_token() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_REWARD_TOKEN_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(REWARD_TOKEN_KEY_NAME, Key::cl_type()),
+            Parameter::new(REWARD_TOKEN_HASH_KEY_NAME, Key::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn add_reward_token() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ADD_REWARD_TOKEN_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(REWARD_TOKEN_KEY_NAME, Key::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn pause() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PAUSE_ENTRY_POINT_NAME),
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn unpause() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UNPAUSE_ENTRY_POINT_NAME),
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn balance_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BALANCE_OF_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS_KEY_NAME, Address::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn earned() -> EntryPoint {
+    EntryPoint::new(
+        String::from(EARNED_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS_KEY_NAME, Address::cl_type()),
+            Parameter::new(REWARD_TOKEN_KEY_NAME, Key::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn reward_per_token() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REWARD_PER_TOKEN_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(REWARD_TOKEN_KEY_NAME, Key::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn total_supply() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TOTAL_SUPPLY_ENTRY_POINT_NAME),
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn reward_rate() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REWARD_RATE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(REWARD_TOKEN_KEY_NAME, Key::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn emission_rates() -> EntryPoint {
+    EntryPoint::new(
+        String::from(EMISSION_RATES_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(REWARD_TOKEN_KEY_NAME, Key::cl_type()),
+        ],
+        <(U256, U256, U256)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn reward_reserve() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REWARD_RESERVE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(REWARD_TOKEN_KEY_NAME, Key::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn outstanding_rewards() -> EntryPoint {
+    EntryPoint::new(
+        String::from(OUTSTANDING_REWARDS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(REWARD_TOKEN_KEY_NAME, Key::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn last_time_reward_applicable() -> EntryPoint {
+    EntryPoint::new(
+        String::from(LAST_TIME_REWARD_APPLICABLE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(REWARD_TOKEN_KEY_NAME, Key::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn effective_balance_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(EFFECTIVE_BALANCE_OF_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS_KEY_NAME, Address::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn locked_balance_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(LOCKED_BALANCE_OF_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS_KEY_NAME, Address::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn unbonding_entries() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UNBONDING_ENTRIES_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS_KEY_NAME, Address::cl_type()),
+        ],
+        Vec::<(U256, U256)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn penalty_schedule() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PENALTY_SCHEDULE_ENTRY_POINT_NAME),
+        vec![],
+        <(U256, U256)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn treasury() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TREASURY_ENTRY_POINT_NAME),
+        vec![],
+        Option::<Address>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn owner() -> EntryPoint {
+    EntryPoint::new(
+        String::from(OWNER_ENTRY_POINT_NAME),
+        vec![],
+        Address::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn is_allowlisted() -> EntryPoint {
+    EntryPoint::new(
+        String::from(IS_ALLOWLISTED_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS_KEY_NAME, Address::cl_type()),
+        ],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn stake_limits() -> EntryPoint {
+    EntryPoint::new(
+        String::from(STAKE_LIMITS_ENTRY_POINT_NAME),
+        vec![],
+        <(U256, U256, U256)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn remaining_capacity() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REMAINING_CAPACITY_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS_KEY_NAME, Address::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn weighted_stake_time() -> EntryPoint {
+    EntryPoint::new(
+        String::from(WEIGHTED_STAKE_TIME_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS_KEY_NAME, Address::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn locked_rewards_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(LOCKED_REWARDS_OF_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS_KEY_NAME, Address::cl_type()),
+            Parameter::new(REWARD_TOKEN_KEY_NAME, Key::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn unlocked_rewards_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UNLOCKED_REWARDS_OF_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS_KEY_NAME, Address::cl_type()),
+            Parameter::new(REWARD_TOKEN_KEY_NAME, Key::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn boost_token() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BOOST_TOKEN_ENTRY_POINT_NAME),
+        vec![],
+        Option::<Key>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn boost_balance_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BOOST_BALANCE_OF_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS_KEY_NAME, Address::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn working_balance_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(WORKING_BALANCE_OF_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS_KEY_NAME, Address::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn working_supply() -> EntryPoint {
+    EntryPoint::new(
+        String::from(WORKING_SUPPLY_ENTRY_POINT_NAME),
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn balance_of_at() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BALANCE_OF_AT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS_KEY_NAME, Address::cl_type()),
+            Parameter::new(TIMESTAMP_KEY_NAME, U256::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn total_supply_at() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TOTAL_SUPPLY_AT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TIMESTAMP_KEY_NAME, U256::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn reward_tokens() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REWARD_TOKENS_ENTRY_POINT_NAME),
+        vec![],
+        Vec::<Key>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the default set of ERC20 token entry points.
 pub(crate) fn default() -> EntryPoints {
     
@@ -50,6 +786,66 @@ pub(crate) fn default() -> EntryPoints {
     entry_points.add_entry_point(stake());
     entry_points.add_entry_point(withdraw());
     entry_points.add_entry_point(get_reward());
+    entry_points.add_entry_point(get_reward_for());
+    entry_points.add_entry_point(stake_for());
+    entry_points.add_entry_point(withdraw_to());
+    entry_points.add_entry_point(get_reward_to());
+    entry_points.add_entry_point(compound());
+    entry_points.add_entry_point(request_withdraw());
+    entry_points.add_entry_point(claim_unbonded());
+    entry_points.add_entry_point(claim_vested());
+    entry_points.add_entry_point(set_boost_token());
+    entry_points.add_entry_point(lock_boost());
+    entry_points.add_entry_point(unlock_boost());
+    entry_points.add_entry_point(emergency_withdraw());
+    entry_points.add_entry_point(stake_locked());
+    entry_points.add_entry_point(unlock_position());
+    entry_points.add_entry_point(set_lock_tiers());
+    entry_points.add_entry_point(add_to_allowlist());
+    entry_points.add_entry_point(remove_from_allowlist());
+    entry_points.add_entry_point(set_allowlist_enabled());
+    entry_points.add_entry_point(set_stake_limits());
+    entry_points.add_entry_point(notify_reward_amount());
+    entry_points.add_entry_point(fund_rewards());
+    entry_points.add_entry_point(recover_tokens());
+    entry_points.add_entry_point(transfer_ownership());
+    entry_points.add_entry_point(accept_ownership());
+    entry_points.add_entry_point(set_reward_rate());
+    entry_points.add_entry_point(set_emission_schedule());
+    entry_points.add_entry_point(set_stake_token());
+    entry_points.add_entry_point(set_reward_token());
+    entry_points.add_entry_point(add_reward_token());
+    entry_points.add_entry_point(migrate());
+    entry_points.add_entry_point(pause());
+    entry_points.add_entry_point(unpause());
+    entry_points.add_entry_point(balance_of());
+    entry_points.add_entry_point(earned());
+    entry_points.add_entry_point(reward_per_token());
+    entry_points.add_entry_point(total_supply());
+    entry_points.add_entry_point(reward_rate());
+    entry_points.add_entry_point(emission_rates());
+    entry_points.add_entry_point(last_time_reward_applicable());
+    entry_points.add_entry_point(effective_balance_of());
+    entry_points.add_entry_point(locked_balance_of());
+    entry_points.add_entry_point(reward_tokens());
+    entry_points.add_entry_point(reward_reserve());
+    entry_points.add_entry_point(outstanding_rewards());
+    entry_points.add_entry_point(unbonding_entries());
+    entry_points.add_entry_point(penalty_schedule());
+    entry_points.add_entry_point(treasury());
+    entry_points.add_entry_point(owner());
+    entry_points.add_entry_point(is_allowlisted());
+    entry_points.add_entry_point(stake_limits());
+    entry_points.add_entry_point(remaining_capacity());
+    entry_points.add_entry_point(weighted_stake_time());
+    entry_points.add_entry_point(locked_rewards_of());
+    entry_points.add_entry_point(unlocked_rewards_of());
+    entry_points.add_entry_point(boost_token());
+    entry_points.add_entry_point(boost_balance_of());
+    entry_points.add_entry_point(working_balance_of());
+    entry_points.add_entry_point(working_supply());
+    entry_points.add_entry_point(balance_of_at());
+    entry_points.add_entry_point(total_supply_at());
 
     entry_points
     
@@ -0,0 +1,96 @@
+//! Error codes used by the Stake contract.
+
+use casper_types::ApiError;
+
+/// Errors the Stake contract reverts with, mapped to `ApiError::User`.
+#[repr(u16)]
+#[derive(Clone, Copy)]
+pub enum StakingError {
+    /// Caller is not allowed to call the entry point.
+    Unauthorized = 1,
+    /// Stake Token can't be changed while tokens are staked.
+    PoolNotEmpty = 2,
+    /// Reward Token can't be changed during a reward period.
+    RewardPeriodActive = 3,
+    /// Staking and reward payouts are stopped by the owner.
+    Paused = 4,
+    /// Amount of tokens to stake or withdraw is zero.
+    ZeroAmount = 5,
+    /// Caller has less tokens staked than requested.
+    InsufficientStake = 6,
+    /// Stake contract is not allowed to transfer enough tokens from the caller.
+    InsufficientAllowance = 7,
+    /// Reward reserve is empty while rewards are owed.
+    RewardPoolEmpty = 8,
+    /// Reward rate is higher than the reward reserve can cover.
+    RewardTooHigh = 9,
+    /// Reward period can't have zero duration.
+    ZeroDuration = 10,
+    /// Arithmetic overflow.
+    Overflow = 11,
+    /// Call stack doesn't have the expected caller.
+    InvalidContext = 12,
+    /// Stake is locked in a position that hasn't expired yet.
+    StakeLocked = 13,
+    /// No lock tier has the requested lock duration.
+    InvalidLockDuration = 14,
+    /// Lock tier has a zero duration or a multiplier below 1x.
+    InvalidLockTier = 15,
+    /// Lock position doesn't exist or is already unlocked.
+    UnknownPosition = 16,
+    /// Token is not one of the reward tokens of the pool.
+    UnknownRewardToken = 17,
+    /// Token is already a reward token of the pool.
+    RewardTokenExists = 18,
+    /// Pool already pays the maximum number of reward tokens.
+    TooManyRewardTokens = 19,
+    // 20 and 21 are not used, errors of the Chef contract only are in `ChefError`
+    /// Rewards can only be compounded when the Stake Token is the Reward Token and they don't vest.
+    CompoundingDisabled = 22,
+    /// Stored state is newer than the installed contract version.
+    InvalidContractVersion = 23,
+    /// Token key is not the hash of an ERC20 contract.
+    InvalidTokenContract = 24,
+    /// Stake has to go through 'request_withdraw' while an unbonding period is set.
+    UnbondingRequired = 25,
+    /// No unbonding entry of the caller has matured yet.
+    NothingToClaim = 26,
+    /// Early-exit penalty rate is above 100%.
+    InvalidPenaltyRate = 27,
+    /// No escrowed reward of the caller has vested yet.
+    NothingVested = 28,
+    /// No Boost Token is set, stake can't be boosted.
+    BoostDisabled = 29,
+    /// Boost Token can't be changed while tokens are locked for boosting.
+    BoostLocked = 30,
+    /// Balances at the requested time can still change, it has to be in the past.
+    SnapshotNotFinal = 31,
+    // 32 to 40 are not used, errors of the Governance contract only are in `GovernanceError`
+    /// Allowlist is enabled and the staker is not on it.
+    NotAllowlisted = 41,
+    /// Staked amount is below the minimum stake.
+    BelowMinimumStake = 42,
+    /// Stake would take the balance of the staker above the per-address cap.
+    StakeCapExceeded = 43,
+    /// Stake would take the total supply above the pool cap.
+    PoolCapExceeded = 44,
+    /// Emission schedule is empty, too long, not sorted by start time, starts in the past
+    /// or doesn't end with a zero rate.
+    InvalidEmissionSchedule = 45,
+    /// Early-exit penalty has neither a treasury nor a reward token slot of the Stake Token to go to.
+    PenaltyNotPayable = 46,
+    /// Token is the Stake Token, the boost token or a reward token of the pool, it can't be recovered.
+    TokenNotRecoverable = 47,
+    /// Reward token can't be replaced while rewards paid in it are still in vesting escrows.
+    RewardsEscrowed = 48,
+    /// Stake Token can't become a reward token, stakes and the reward reserve would share one balance.
+    RewardTokenIsStakeToken = 49,
+    /// Rewards vest in the escrow of the staker, they can't be paid to another recipient.
+    RecipientWhileVesting = 50,
+}
+
+impl From<StakingError> for ApiError {
+    fn from(error: StakingError) -> Self {
+        ApiError::User(error as u16)
+    }
+}
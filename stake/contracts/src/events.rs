@@ -0,0 +1,132 @@
+//! Events emitted by the Stake contract.
+//!
+//! Events are appended to the "events" dictionary, the dictionary item key of an event is its
+//! index as a decimal string ("0", "1", ...). Indexes increase by one with every event and
+//! "event_count" named key holds the number of events emitted so far, so an indexer can read
+//! new events from its last seen index up to "event_count" - 1.
+//!
+//! Every event is stored as a `BTreeMap<String, String>` with the following items:
+//!
+//! - "event_type": one of "Staked", "Withdrawn", "RewardPaid", "RewardAdded", "RewardRateUpdated",
+//!   "Compounded", "WithdrawRequested", "PenaltyCharged", "VestedClaimed", "BoostLocked",
+//!   "BoostUnlocked", "EmissionScheduleUpdated"
+//! - "address": formatted `Key` of the account or contract the event is about
+//!   ("account-hash-..." or "hash-..."), for "RewardAdded", "RewardRateUpdated" and
+//!   "EmissionScheduleUpdated" it is the caller
+//! - "amount": decimal amount of tokens, for "RewardRateUpdated" it is the new reward rate,
+//!   for "EmissionScheduleUpdated" the reward rate of the schedule at the block time
+//! - "blocktime": decimal block time the event was emitted at
+//! - "reward_token": formatted `Key` of the reward token, only for "RewardPaid", "RewardAdded",
+//!   "RewardRateUpdated", "VestedClaimed" and "EmissionScheduleUpdated"
+//! - "segment_count": decimal number of segments of the new schedule, only for
+//!   "EmissionScheduleUpdated"
+//!
+//! Items are only ever added to this format, existing ones are not renamed or removed.
+
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
+
+use casper_contract::{contract_api::{runtime, storage}, unwrap_or_revert::UnwrapOrRevert};
+use casper_erc20::Address;
+use casper_types::{Key, URef, U256};
+
+use crate::{
+    constants::{EVENTS_KEY_NAME, EVENT_COUNT_KEY_NAME},
+    helpers::{get_key, set_key},
+};
+
+pub(crate) enum StakingEvent {
+    Staked { staker: Address, amount: U256 },
+    Withdrawn { staker: Address, amount: U256 },
+    RewardPaid { staker: Address, amount: U256, reward_token: Key },
+    RewardAdded { funder: Address, amount: U256, reward_token: Key },
+    RewardRateUpdated { caller: Address, reward_rate: U256, reward_token: Key },
+    Compounded { staker: Address, amount: U256 },
+    WithdrawRequested { staker: Address, amount: U256 },
+    PenaltyCharged { staker: Address, amount: U256 },
+    VestedClaimed { staker: Address, amount: U256, reward_token: Key },
+    BoostLocked { staker: Address, amount: U256 },
+    BoostUnlocked { staker: Address, amount: U256 },
+    EmissionScheduleUpdated { caller: Address, reward_rate: U256, reward_token: Key, segment_count: usize },
+}
+
+impl StakingEvent {
+    fn event_type(&self) -> &'static str {
+        match self {
+            StakingEvent::Staked { .. } => "Staked",
+            StakingEvent::Withdrawn { .. } => "Withdrawn",
+            StakingEvent::RewardPaid { .. } => "RewardPaid",
+            StakingEvent::RewardAdded { .. } => "RewardAdded",
+            StakingEvent::RewardRateUpdated { .. } => "RewardRateUpdated",
+            StakingEvent::Compounded { .. } => "Compounded",
+            StakingEvent::WithdrawRequested { .. } => "WithdrawRequested",
+            StakingEvent::PenaltyCharged { .. } => "PenaltyCharged",
+            StakingEvent::VestedClaimed { .. } => "VestedClaimed",
+            StakingEvent::BoostLocked { .. } => "BoostLocked",
+            StakingEvent::BoostUnlocked { .. } => "BoostUnlocked",
+            StakingEvent::EmissionScheduleUpdated { .. } => "EmissionScheduleUpdated",
+        }
+    }
+
+    fn address_and_amount(&self) -> (Address, U256) {
+        match *self {
+            StakingEvent::Staked { staker, amount }
+            | StakingEvent::Withdrawn { staker, amount }
+            | StakingEvent::RewardPaid { staker, amount, .. }
+            | StakingEvent::VestedClaimed { staker, amount, .. }
+            | StakingEvent::Compounded { staker, amount }
+            | StakingEvent::WithdrawRequested { staker, amount }
+            | StakingEvent::PenaltyCharged { staker, amount }
+            | StakingEvent::BoostLocked { staker, amount }
+            | StakingEvent::BoostUnlocked { staker, amount } => (staker, amount),
+            StakingEvent::RewardAdded { funder, amount, .. } => (funder, amount),
+            StakingEvent::RewardRateUpdated { caller, reward_rate, .. }
+            | StakingEvent::EmissionScheduleUpdated { caller, reward_rate, .. } => (caller, reward_rate),
+        }
+    }
+
+    fn reward_token(&self) -> Option<Key> {
+        match *self {
+            StakingEvent::RewardPaid { reward_token, .. }
+            | StakingEvent::VestedClaimed { reward_token, .. }
+            | StakingEvent::RewardAdded { reward_token, .. }
+            | StakingEvent::RewardRateUpdated { reward_token, .. }
+            | StakingEvent::EmissionScheduleUpdated { reward_token, .. } => Some(reward_token),
+            _ => None,
+        }
+    }
+
+    fn segment_count(&self) -> Option<usize> {
+        match *self {
+            StakingEvent::EmissionScheduleUpdated { segment_count, .. } => Some(segment_count),
+            _ => None,
+        }
+    }
+}
+
+/// Appends `event` to the "events" dictionary under the next index
+pub(crate) fn emit(event: StakingEvent) {
+    let (address, amount) = event.address_and_amount();
+    let block_time: u64 = runtime::get_blocktime().into();
+
+    let mut event_item: BTreeMap<String, String> = BTreeMap::new();
+    event_item.insert("event_type".to_string(), event.event_type().to_string());
+    event_item.insert("address".to_string(), Key::from(address).to_formatted_string());
+    event_item.insert("amount".to_string(), amount.to_string());
+    event_item.insert("blocktime".to_string(), block_time.to_string());
+    if let Some(reward_token) = event.reward_token() {
+        event_item.insert("reward_token".to_string(), reward_token.to_formatted_string());
+    }
+    if let Some(segment_count) = event.segment_count() {
+        event_item.insert("segment_count".to_string(), segment_count.to_string());
+    }
+
+    let events_key: Key = runtime::get_key(EVENTS_KEY_NAME).unwrap_or_revert();
+    let events_uref: URef = events_key.into_uref().unwrap_or_revert();
+    let event_count: u64 = get_key(EVENT_COUNT_KEY_NAME).unwrap_or_revert();
+
+    storage::dictionary_put(events_uref, &event_count.to_string(), event_item);
+    set_key(EVENT_COUNT_KEY_NAME, event_count + 1);
+}
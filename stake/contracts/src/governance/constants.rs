@@ -0,0 +1,76 @@
+//! Constants used by the Governance contract.
+
+pub const GOVERNANCE_CONTRACT_KEY_NAME: &str = "governance_contract";
+
+/// Quorum and threshold are in basis points, 10_000 is all the votes
+pub const VOTING_RULE_BASE: u64 = 10_000;
+
+// Named keys
+
+pub const STAKING_CONTRACT_PACKAGE_HASH_KEY_NAME: &str = "staking_contract_package_hash";
+
+pub const VOTING_PERIOD_KEY_NAME: &str = "voting_period";
+
+pub const TIMELOCK_DELAY_KEY_NAME: &str = "timelock_delay";
+
+pub const QUORUM_KEY_NAME: &str = "quorum";
+
+pub const THRESHOLD_KEY_NAME: &str = "threshold";
+
+pub const PROPOSAL_COUNT_KEY_NAME: &str = "proposal_count";
+
+// Dictionaries of proposals, the dictionary item key is the proposal id
+
+pub const PROPOSAL_TARGETS_KEY_NAME: &str = "proposal_targets";
+
+pub const PROPOSAL_ENTRY_POINTS_KEY_NAME: &str = "proposal_entry_points";
+
+pub const PROPOSAL_ARGS_KEY_NAME: &str = "proposal_args";
+
+pub const PROPOSAL_SNAPSHOTS_KEY_NAME: &str = "proposal_snapshots";
+
+pub const PROPOSAL_VOTING_ENDS_KEY_NAME: &str = "proposal_voting_ends";
+
+pub const PROPOSAL_FOR_VOTES_KEY_NAME: &str = "proposal_for_votes";
+
+pub const PROPOSAL_AGAINST_VOTES_KEY_NAME: &str = "proposal_against_votes";
+
+pub const PROPOSAL_EXECUTED_KEY_NAME: &str = "proposal_executed";
+
+// Dictionaries of voters, the dictionary item key is the address and the proposal id
+
+pub const VOTES_KEY_NAME: &str = "votes";
+
+// Entry points
+
+pub const PROPOSE_ENTRY_POINT_NAME: &str = "propose";
+
+pub const VOTE_ENTRY_POINT_NAME: &str = "vote";
+
+pub const EXECUTE_ENTRY_POINT_NAME: &str = "execute";
+
+// Views
+
+pub const PROPOSAL_VOTES_ENTRY_POINT_NAME: &str = "proposal_votes";
+
+// Entry points of the Stake contract
+
+pub const BALANCE_OF_AT_ENTRY_POINT_NAME: &str = "balance_of_at";
+
+pub const TOTAL_SUPPLY_AT_ENTRY_POINT_NAME: &str = "total_supply_at";
+
+// Runtime argument names
+
+pub const TARGET_KEY_NAME: &str = "target";
+
+pub const ENTRY_POINT_KEY_NAME: &str = "entry_point";
+
+pub const ARGS_KEY_NAME: &str = "args";
+
+pub const PROPOSAL_ID_KEY_NAME: &str = "proposal_id";
+
+pub const SUPPORT_KEY_NAME: &str = "support";
+
+pub const ADDRESS_KEY_NAME: &str = "address";
+
+pub const TIMESTAMP_KEY_NAME: &str = "timestamp";
@@ -0,0 +1,76 @@
+use alloc::{string::String, vec};
+
+use crate::constants::{
+    PROPOSE_ENTRY_POINT_NAME, VOTE_ENTRY_POINT_NAME, EXECUTE_ENTRY_POINT_NAME,
+    PROPOSAL_VOTES_ENTRY_POINT_NAME, TARGET_KEY_NAME, ENTRY_POINT_KEY_NAME, ARGS_KEY_NAME,
+    PROPOSAL_ID_KEY_NAME, SUPPORT_KEY_NAME
+    };
+
+use casper_types::{
+    bytesrepr::Bytes, U256, Key, CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType,
+    EntryPoints, Parameter
+    };
+
+fn propose() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PROPOSE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TARGET_KEY_NAME, Key::cl_type()),
+            Parameter::new(ENTRY_POINT_KEY_NAME, String::cl_type()),
+            Parameter::new(ARGS_KEY_NAME, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn vote() -> EntryPoint {
+    EntryPoint::new(
+        String::from(VOTE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(PROPOSAL_ID_KEY_NAME, u32::cl_type()),
+            Parameter::new(SUPPORT_KEY_NAME, bool::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn execute() -> EntryPoint {
+    EntryPoint::new(
+        String::from(EXECUTE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(PROPOSAL_ID_KEY_NAME, u32::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn proposal_votes() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PROPOSAL_VOTES_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(PROPOSAL_ID_KEY_NAME, u32::cl_type()),
+        ],
+        <(U256, U256)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the default set of Governance entry points.
+pub(crate) fn default() -> EntryPoints {
+    
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(propose());
+    entry_points.add_entry_point(vote());
+    entry_points.add_entry_point(execute());
+    entry_points.add_entry_point(proposal_votes());
+
+    entry_points
+    
+}
@@ -0,0 +1,35 @@
+//! Error codes only the Governance contract reverts with.
+
+use casper_types::ApiError;
+
+/// Errors of the Governance contract, mapped to `ApiError::User`. Codes start at 2000,
+/// clear of the `StakingError` codes the Governance contract reverts with as well
+/// and of the `ChefError` codes.
+#[repr(u16)]
+#[derive(Clone, Copy)]
+pub enum GovernanceError {
+    /// Governance has no proposal with the requested id.
+    UnknownProposal = 2000,
+    /// Voting period of the proposal is over.
+    VotingClosed = 2001,
+    /// Caller has already voted on the proposal.
+    AlreadyVoted = 2002,
+    /// Caller had no stake at the snapshot of the proposal.
+    NoVotingPower = 2003,
+    /// Proposal is still being voted on or didn't reach the quorum and the threshold.
+    ProposalNotPassed = 2004,
+    /// Timelock delay of the proposal is not over yet.
+    TimelockActive = 2005,
+    /// Proposal has already been executed.
+    ProposalExecuted = 2006,
+    /// Quorum or threshold is above 100%.
+    InvalidVotingRule = 2007,
+    /// Proposal target is not the hash of a contract package.
+    InvalidProposalTarget = 2008,
+}
+
+impl From<GovernanceError> for ApiError {
+    fn from(error: GovernanceError) -> Self {
+        ApiError::User(error as u16)
+    }
+}
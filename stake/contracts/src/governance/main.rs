@@ -0,0 +1,320 @@
+//! Governance contract: stakers of the Stake contract vote on proposals calling any contract,
+//! the admin entry points of the Stake and Chef contracts once they are owned by the Governance
+//! contract.
+//!
+//! Voting power is the stake of a user at the proposal's snapshot, read from the balance
+//! checkpoints of the Stake contract, so tokens staked after a proposal is made don't vote on it.
+//! A proposal passes when its votes for reach the quorum and the threshold at the end of the
+//! voting period, anyone can execute it after the timelock delay.
+
+#![no_main]
+#![no_std]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+// Shared with the Stake contract
+#[allow(dead_code)]
+#[path = "../helpers.rs"]
+mod helpers;
+#[allow(dead_code)]
+#[path = "../error.rs"]
+mod error;
+
+mod entry_points;
+mod named_keys;
+mod governance_error;
+pub mod constants;
+
+use crate::helpers::{
+    set_key, get_key, get_immediate_caller_address, dictionary_get, make_indexed_item_key
+};
+
+use crate::constants::{
+    GOVERNANCE_CONTRACT_KEY_NAME, VOTING_RULE_BASE, STAKING_CONTRACT_PACKAGE_HASH_KEY_NAME,
+    VOTING_PERIOD_KEY_NAME, TIMELOCK_DELAY_KEY_NAME, QUORUM_KEY_NAME, THRESHOLD_KEY_NAME,
+    PROPOSAL_COUNT_KEY_NAME, PROPOSAL_TARGETS_KEY_NAME, PROPOSAL_ENTRY_POINTS_KEY_NAME,
+    PROPOSAL_ARGS_KEY_NAME, PROPOSAL_SNAPSHOTS_KEY_NAME, PROPOSAL_VOTING_ENDS_KEY_NAME,
+    PROPOSAL_FOR_VOTES_KEY_NAME, PROPOSAL_AGAINST_VOTES_KEY_NAME, PROPOSAL_EXECUTED_KEY_NAME,
+    VOTES_KEY_NAME, BALANCE_OF_AT_ENTRY_POINT_NAME, TOTAL_SUPPLY_AT_ENTRY_POINT_NAME,
+    TARGET_KEY_NAME, ENTRY_POINT_KEY_NAME, ARGS_KEY_NAME, PROPOSAL_ID_KEY_NAME, SUPPORT_KEY_NAME,
+    ADDRESS_KEY_NAME, TIMESTAMP_KEY_NAME,
+};
+
+use crate::error::StakingError;
+use crate::governance_error::GovernanceError;
+
+use alloc::string::{String, ToString};
+
+use casper_erc20::Address;
+
+use casper_contract::{contract_api::{runtime, storage}, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    bytesrepr::{Bytes, FromBytes, ToBytes}, contracts::NamedKeys, CLTyped, U256, ContractPackageHash,
+    Key, URef, RuntimeArgs, runtime_args, CLValue};
+
+#[no_mangle]
+fn call() {
+
+    let contract_name: String = runtime::get_named_arg(GOVERNANCE_CONTRACT_KEY_NAME);
+    let contract_hash_key_name = contract_name.clone();
+    let contract_package_hash_key_name = contract_name.clone() + "_package_hash";
+
+    let staking_contract_package_hash_key: Key = runtime::get_named_arg(STAKING_CONTRACT_PACKAGE_HASH_KEY_NAME);
+    let voting_period: U256 = runtime::get_named_arg(VOTING_PERIOD_KEY_NAME);
+    let timelock_delay: U256 = runtime::get_named_arg(TIMELOCK_DELAY_KEY_NAME);
+    let quorum: U256 = runtime::get_named_arg(QUORUM_KEY_NAME);
+    let threshold: U256 = runtime::get_named_arg(THRESHOLD_KEY_NAME);
+
+    if quorum > U256::from(VOTING_RULE_BASE) || threshold >= U256::from(VOTING_RULE_BASE) {
+        runtime::revert(GovernanceError::InvalidVotingRule);
+    }
+
+    let named_keys: NamedKeys = named_keys::default(
+        contract_name,
+        staking_contract_package_hash_key,
+        voting_period,
+        timelock_delay,
+        quorum,
+        threshold
+    );
+
+    // We store contract on-chain, nobody can change the rules of a running governance
+    let (contract_hash, _version) = storage::new_locked_contract(
+        entry_points::default(),
+        Some(named_keys),
+        Some(contract_package_hash_key_name),
+        None
+    );
+
+    runtime::put_key(contract_hash_key_name.as_str(), Key::from(contract_hash));
+}
+
+/// Proposes to call `entry_point` of the latest version of the contract package `target`
+/// with the serialized `args`. Proposer needs some voting power right before the proposal
+#[no_mangle]
+pub extern "C" fn propose() {
+
+    let target: Key = runtime::get_named_arg(TARGET_KEY_NAME);
+    let entry_point: String = runtime::get_named_arg(ENTRY_POINT_KEY_NAME);
+    let args: Bytes = runtime::get_named_arg(ARGS_KEY_NAME);
+
+    // Only contract packages can be called
+    target.into_hash().unwrap_or_revert_with(GovernanceError::InvalidProposalTarget);
+
+    let proposer: Address = get_immediate_caller_address().unwrap_or_revert();
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+
+    // Stake of the block the proposal is made in doesn't count, it can't be flash staked
+    let snapshot: U256 = current_block_time.saturating_sub(U256::one());
+
+    if voting_power(proposer, snapshot).is_zero() {
+        runtime::revert(GovernanceError::NoVotingPower);
+    }
+
+    let voting_period: U256 = get_key(VOTING_PERIOD_KEY_NAME).unwrap_or_revert();
+    let voting_end: U256 = current_block_time
+        .checked_add(voting_period)
+        .ok_or(StakingError::Overflow).unwrap_or_revert();
+
+    let proposal_id: u32 = get_key(PROPOSAL_COUNT_KEY_NAME).unwrap_or_revert();
+
+    proposal_write(PROPOSAL_TARGETS_KEY_NAME, proposal_id, target);
+    proposal_write(PROPOSAL_ENTRY_POINTS_KEY_NAME, proposal_id, entry_point);
+    proposal_write(PROPOSAL_ARGS_KEY_NAME, proposal_id, args);
+    proposal_write(PROPOSAL_SNAPSHOTS_KEY_NAME, proposal_id, snapshot);
+    proposal_write(PROPOSAL_VOTING_ENDS_KEY_NAME, proposal_id, voting_end);
+    proposal_write(PROPOSAL_FOR_VOTES_KEY_NAME, proposal_id, U256::from(0));
+    proposal_write(PROPOSAL_AGAINST_VOTES_KEY_NAME, proposal_id, U256::from(0));
+    proposal_write(PROPOSAL_EXECUTED_KEY_NAME, proposal_id, false);
+
+    set_key(PROPOSAL_COUNT_KEY_NAME, proposal_id + 1);
+}
+
+/// Casts the whole voting power of the caller at the snapshot of `proposal_id`, once
+#[no_mangle]
+pub extern "C" fn vote() {
+
+    let proposal_id: u32 = runtime::get_named_arg(PROPOSAL_ID_KEY_NAME);
+    let support: bool = runtime::get_named_arg(SUPPORT_KEY_NAME);
+
+    check_proposal(proposal_id);
+
+    let voter: Address = get_immediate_caller_address().unwrap_or_revert();
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+    let voting_end: U256 = proposal_read(PROPOSAL_VOTING_ENDS_KEY_NAME, proposal_id).unwrap_or_revert();
+
+    if current_block_time > voting_end {
+        runtime::revert(GovernanceError::VotingClosed);
+    }
+
+    let vote_item_key: String = make_indexed_item_key(voter, proposal_id);
+    let votes_uref: URef = dictionary_uref(VOTES_KEY_NAME);
+
+    let cast: U256 = dictionary_get(votes_uref, &vote_item_key).unwrap_or_default();
+    if !cast.is_zero() {
+        runtime::revert(GovernanceError::AlreadyVoted);
+    }
+
+    let snapshot: U256 = proposal_read(PROPOSAL_SNAPSHOTS_KEY_NAME, proposal_id).unwrap_or_revert();
+    let power: U256 = voting_power(voter, snapshot);
+
+    if power.is_zero() {
+        runtime::revert(GovernanceError::NoVotingPower);
+    }
+
+    storage::dictionary_put(votes_uref, &vote_item_key, power);
+
+    if support {
+        proposal_add(PROPOSAL_FOR_VOTES_KEY_NAME, proposal_id, power);
+    } else {
+        proposal_add(PROPOSAL_AGAINST_VOTES_KEY_NAME, proposal_id, power);
+    }
+}
+
+/// Calls the entry point of a passed proposal once its timelock delay is over, anyone can execute it
+#[no_mangle]
+pub extern "C" fn execute() {
+
+    let proposal_id: u32 = runtime::get_named_arg(PROPOSAL_ID_KEY_NAME);
+
+    check_proposal(proposal_id);
+
+    let executed: bool = proposal_read(PROPOSAL_EXECUTED_KEY_NAME, proposal_id).unwrap_or_revert();
+    if executed {
+        runtime::revert(GovernanceError::ProposalExecuted);
+    }
+
+    if !is_passed(proposal_id) {
+        runtime::revert(GovernanceError::ProposalNotPassed);
+    }
+
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+    let voting_end: U256 = proposal_read(PROPOSAL_VOTING_ENDS_KEY_NAME, proposal_id).unwrap_or_revert();
+    let timelock_delay: U256 = get_key(TIMELOCK_DELAY_KEY_NAME).unwrap_or_revert();
+    let executable_time: U256 = voting_end
+        .checked_add(timelock_delay)
+        .ok_or(StakingError::Overflow).unwrap_or_revert();
+
+    if current_block_time < executable_time {
+        runtime::revert(GovernanceError::TimelockActive);
+    }
+
+    // marked before the call, the target can't execute it again
+    proposal_write(PROPOSAL_EXECUTED_KEY_NAME, proposal_id, true);
+
+    let target: Key = proposal_read(PROPOSAL_TARGETS_KEY_NAME, proposal_id).unwrap_or_revert();
+    let entry_point: String = proposal_read(PROPOSAL_ENTRY_POINTS_KEY_NAME, proposal_id).unwrap_or_revert();
+    let args: Bytes = proposal_read(PROPOSAL_ARGS_KEY_NAME, proposal_id).unwrap_or_revert();
+
+    let (runtime_args, _): (RuntimeArgs, _) = RuntimeArgs::from_bytes(&args).unwrap_or_revert();
+    let target_package_hash: ContractPackageHash = ContractPackageHash::new(target.into_hash().unwrap_or_revert());
+
+    // The latest version is called, proposals survive upgrades of their target
+    let _: () = runtime::call_versioned_contract(target_package_hash, None, &entry_point, runtime_args);
+}
+
+/// Whether the voting period of `proposal_id` is over and its votes for reach
+/// the quorum and the threshold
+fn is_passed(proposal_id: u32) -> bool {
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+    let voting_end: U256 = proposal_read(PROPOSAL_VOTING_ENDS_KEY_NAME, proposal_id).unwrap_or_revert();
+
+    if current_block_time <= voting_end {
+        return false;
+    }
+
+    let for_votes: U256 = proposal_read(PROPOSAL_FOR_VOTES_KEY_NAME, proposal_id).unwrap_or_revert();
+    let against_votes: U256 = proposal_read(PROPOSAL_AGAINST_VOTES_KEY_NAME, proposal_id).unwrap_or_revert();
+    let snapshot: U256 = proposal_read(PROPOSAL_SNAPSHOTS_KEY_NAME, proposal_id).unwrap_or_revert();
+    let quorum: U256 = get_key(QUORUM_KEY_NAME).unwrap_or_revert();
+    let threshold: U256 = get_key(THRESHOLD_KEY_NAME).unwrap_or_revert();
+
+    let scaled_for_votes: U256 = for_votes
+        .checked_mul(U256::from(VOTING_RULE_BASE))
+        .ok_or(StakingError::Overflow).unwrap_or_revert();
+
+    let quorum_votes: U256 = total_voting_power(snapshot)
+        .checked_mul(quorum)
+        .ok_or(StakingError::Overflow).unwrap_or_revert();
+
+    let threshold_votes: U256 = for_votes
+        .checked_add(against_votes)
+        .and_then(|votes| votes.checked_mul(threshold))
+        .ok_or(StakingError::Overflow).unwrap_or_revert();
+
+    scaled_for_votes >= quorum_votes && scaled_for_votes > threshold_votes
+}
+
+/// Reverts unless `proposal_id` was made
+fn check_proposal(proposal_id: u32) {
+    let proposal_count: u32 = get_key(PROPOSAL_COUNT_KEY_NAME).unwrap_or_revert();
+
+    if proposal_id >= proposal_count {
+        runtime::revert(GovernanceError::UnknownProposal);
+    }
+}
+
+/// Stake of `address` in the Stake contract at the end of `snapshot`
+fn voting_power(address: Address, snapshot: U256) -> U256 {
+    runtime::call_versioned_contract(staking_contract_package_hash(), None, BALANCE_OF_AT_ENTRY_POINT_NAME, runtime_args!{
+        ADDRESS_KEY_NAME => address,
+        TIMESTAMP_KEY_NAME => snapshot
+    })
+}
+
+/// Total stake in the Stake contract at the end of `snapshot`
+fn total_voting_power(snapshot: U256) -> U256 {
+    runtime::call_versioned_contract(staking_contract_package_hash(), None, TOTAL_SUPPLY_AT_ENTRY_POINT_NAME, runtime_args!{
+        TIMESTAMP_KEY_NAME => snapshot
+    })
+}
+
+fn staking_contract_package_hash() -> ContractPackageHash {
+    let staking_contract_package_hash_key: Key = get_key(STAKING_CONTRACT_PACKAGE_HASH_KEY_NAME).unwrap_or_revert();
+
+    ContractPackageHash::new(staking_contract_package_hash_key.into_hash().unwrap_or_revert())
+}
+
+// Views, results are returned to the caller with `runtime::ret`
+
+/// (votes for, votes against) cast on `proposal_id` so far
+#[no_mangle]
+pub extern "C" fn proposal_votes() {
+
+    let proposal_id: u32 = runtime::get_named_arg(PROPOSAL_ID_KEY_NAME);
+
+    check_proposal(proposal_id);
+
+    let for_votes: U256 = proposal_read(PROPOSAL_FOR_VOTES_KEY_NAME, proposal_id).unwrap_or_revert();
+    let against_votes: U256 = proposal_read(PROPOSAL_AGAINST_VOTES_KEY_NAME, proposal_id).unwrap_or_revert();
+
+    runtime::ret(CLValue::from_t((for_votes, against_votes)).unwrap_or_revert());
+}
+
+fn dictionary_uref(dictionary_name: &str) -> URef {
+    let dictionary_key: Key = runtime::get_key(dictionary_name).unwrap_or_revert();
+
+    dictionary_key.into_uref().unwrap_or_revert()
+}
+
+/// Reads the item of `proposal_id` from the proposal dictionary `dictionary_name`
+fn proposal_read<T: FromBytes + CLTyped>(dictionary_name: &str, proposal_id: u32) -> Option<T> {
+    dictionary_get(dictionary_uref(dictionary_name), &proposal_id.to_string())
+}
+
+fn proposal_write<T: ToBytes + CLTyped>(dictionary_name: &str, proposal_id: u32, value: T) {
+    storage::dictionary_put(dictionary_uref(dictionary_name), &proposal_id.to_string(), value);
+}
+
+fn proposal_add(dictionary_name: &str, proposal_id: u32, amount: U256) {
+    let value: U256 = proposal_read(dictionary_name, proposal_id).unwrap_or_default();
+
+    proposal_write(
+        dictionary_name,
+        proposal_id,
+        value.checked_add(amount).ok_or(StakingError::Overflow).unwrap_or_revert()
+    );
+}
@@ -0,0 +1,123 @@
+use casper_types::{U256, URef, contracts::NamedKeys, Key};
+use alloc::string::{String, ToString};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert
+};
+
+use crate::constants::{
+    GOVERNANCE_CONTRACT_KEY_NAME, STAKING_CONTRACT_PACKAGE_HASH_KEY_NAME, VOTING_PERIOD_KEY_NAME,
+    TIMELOCK_DELAY_KEY_NAME, QUORUM_KEY_NAME, THRESHOLD_KEY_NAME, PROPOSAL_COUNT_KEY_NAME,
+    PROPOSAL_TARGETS_KEY_NAME, PROPOSAL_ENTRY_POINTS_KEY_NAME, PROPOSAL_ARGS_KEY_NAME,
+    PROPOSAL_SNAPSHOTS_KEY_NAME, PROPOSAL_VOTING_ENDS_KEY_NAME, PROPOSAL_FOR_VOTES_KEY_NAME,
+    PROPOSAL_AGAINST_VOTES_KEY_NAME, PROPOSAL_EXECUTED_KEY_NAME, VOTES_KEY_NAME
+};
+
+pub fn default(
+    governance_contract_name: String,
+    staking_contract_package_hash_key: Key,
+    voting_period: U256,
+    timelock_delay: U256,
+    quorum: U256,
+    threshold: U256
+            ) -> NamedKeys {
+    
+    let mut named_keys = NamedKeys::new();
+
+    // Contract 'Named keys'
+
+    // 0. Name of the Governance contract
+    let governance_contract_name_key = {
+        let governance_contract_name_uref = storage::new_uref(governance_contract_name).into_read();
+        Key::from(governance_contract_name_uref)
+    };
+
+    // 1. 'Contract Package Hash' of the Stake contract voting power is read from, read only
+    let staking_contract_package_hash_key = {
+        let staking_contract_package_hash_uref: URef = storage::new_uref(staking_contract_package_hash_key).into_read();
+        Key::from(staking_contract_package_hash_uref)
+    };
+
+    // 2. "voting_period", read only
+    // Time a proposal can be voted on after it is made
+    let voting_period_key = {
+        let voting_period_uref = storage::new_uref(voting_period).into_read();
+        Key::from(voting_period_uref)
+    };
+
+    // 3. "timelock_delay", read only
+    // Time a passed proposal waits after its voting period before it can be executed
+    let timelock_delay_key = {
+        let timelock_delay_uref = storage::new_uref(timelock_delay).into_read();
+        Key::from(timelock_delay_uref)
+    };
+
+    // 4. "quorum" and "threshold", read only
+    // Votes for a proposal have to reach "quorum" basis points of the total stake at its snapshot,
+    // and be more than "threshold" basis points of the votes cast
+    let quorum_key = {
+        let quorum_uref = storage::new_uref(quorum).into_read();
+        Key::from(quorum_uref)
+    };
+
+    let threshold_key = {
+        let threshold_uref = storage::new_uref(threshold).into_read();
+        Key::from(threshold_uref)
+    };
+
+    // 5. "proposal_count", read and write
+    // Number of proposals, id of the next proposal
+    let proposal_count: u32 = 0;
+    let proposal_count_key = {
+        let proposal_count_uref = storage::new_uref(proposal_count).into_read_write();
+        Key::from(proposal_count_uref)
+    };
+
+    named_keys.insert(GOVERNANCE_CONTRACT_KEY_NAME.to_string(), governance_contract_name_key);
+    named_keys.insert(STAKING_CONTRACT_PACKAGE_HASH_KEY_NAME.to_string(), staking_contract_package_hash_key);
+    named_keys.insert(VOTING_PERIOD_KEY_NAME.to_string(), voting_period_key);
+    named_keys.insert(TIMELOCK_DELAY_KEY_NAME.to_string(), timelock_delay_key);
+    named_keys.insert(QUORUM_KEY_NAME.to_string(), quorum_key);
+    named_keys.insert(THRESHOLD_KEY_NAME.to_string(), threshold_key);
+    named_keys.insert(PROPOSAL_COUNT_KEY_NAME.to_string(), proposal_count_key);
+
+    // Contract 'Dictionaries'
+
+    // Proposals, keyed by proposal id
+    for dictionary_name in [
+        // 'Contract Package Hash' of the contract the proposal calls
+        PROPOSAL_TARGETS_KEY_NAME,
+        // Entry point the proposal calls
+        PROPOSAL_ENTRY_POINTS_KEY_NAME,
+        // Serialized 'RuntimeArgs' of the call
+        PROPOSAL_ARGS_KEY_NAME,
+        // Block time voting power is read at, right before the proposal was made
+        PROPOSAL_SNAPSHOTS_KEY_NAME,
+        // Last block time votes are accepted at
+        PROPOSAL_VOTING_ENDS_KEY_NAME,
+        // Voting power cast for the proposal
+        PROPOSAL_FOR_VOTES_KEY_NAME,
+        // Voting power cast against the proposal
+        PROPOSAL_AGAINST_VOTES_KEY_NAME,
+        // Whether the proposal was executed
+        PROPOSAL_EXECUTED_KEY_NAME,
+    ] {
+        let dictionary_uref: URef = storage::new_dictionary(dictionary_name).unwrap_or_revert();
+        named_keys.insert(dictionary_name.to_string(), Key::from(dictionary_uref));
+        // only the contract keeps it, so the installer can install more contracts
+        runtime::remove_key(dictionary_name);
+    }
+
+    // Voters, keyed by address and proposal id
+    for dictionary_name in [
+        // Voting power cast by a user on a proposal, zero until it votes
+        VOTES_KEY_NAME,
+    ] {
+        let dictionary_uref: URef = storage::new_dictionary(dictionary_name).unwrap_or_revert();
+        named_keys.insert(dictionary_name.to_string(), Key::from(dictionary_uref));
+        // only the contract keeps it, so the installer can install more contracts
+        runtime::remove_key(dictionary_name);
+    }
+    
+    named_keys
+}
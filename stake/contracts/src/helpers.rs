@@ -5,8 +5,8 @@ use casper_contract::{contract_api::{runtime, storage}, unwrap_or_revert::Unwrap
 use casper_types::{URef, system::CallStackElement, U256};
 use casper_erc20::Address;
 use core::convert::TryInto;
-use casper_erc20::Error;
-use alloc::string::String;
+use crate::error::StakingError;
+use alloc::{format, string::{String, ToString}};
 
 // Helper functions
 
@@ -34,10 +34,10 @@ pub(crate) fn set_key<T: ToBytes + CLTyped>(name: &str, value: T) {
     }
 }
 
-pub(crate) fn get_self_address() -> Result<Address, Error> {
+pub(crate) fn get_self_address() -> Result<Address, StakingError> {
     get_last_call_stack_item()
         .map(call_stack_element_to_address)
-        .ok_or(Error::InvalidContext)
+        .ok_or(StakingError::InvalidContext)
 }
 
 fn get_last_call_stack_item() -> Option<CallStackElement> {
@@ -74,10 +74,10 @@ fn call_stack_element_to_address(call_stack_element: CallStackElement) -> Addres
 ///
 /// This function ensures that only session code can execute this function, and disallows stored
 /// session/stored contracts.
-pub(crate) fn get_immediate_caller_address() -> Result<Address, Error> {
+pub(crate) fn get_immediate_caller_address() -> Result<Address, StakingError> {
     get_immediate_call_stack_item()
         .map(call_stack_element_to_address)
-        .ok_or(Error::InvalidContext)
+        .ok_or(StakingError::InvalidContext)
 }
 
 #[no_mangle]
@@ -88,7 +88,7 @@ pub(crate) fn dictionary_write(dictionary_uref: URef, address: Address, amount:
 
 /// Creates a dictionary item key for a dictionary item.
 #[no_mangle]
-fn make_dictionary_item_key(owner: Address) -> String {
+pub(crate) fn make_dictionary_item_key(owner: Address) -> String {
     let preimage = owner.to_bytes().unwrap_or_revert();
     // NOTE: As for now dictionary item keys are limited to 64 characters only. Instead of using
     // hashing (which will effectively hash a hash) we'll use base64. Preimage is about 33 bytes for
@@ -106,4 +106,29 @@ pub(crate) fn dictionary_read(dictionary_uref: URef, address: Address) -> U256 {
     storage::dictionary_get(dictionary_uref, &dictionary_item_key)
         .unwrap_or_revert()
         .unwrap_or_default()
+}
+
+/// Reads a dictionary item of any type, `None` if it was never written.
+pub(crate) fn dictionary_get<T: FromBytes + CLTyped>(dictionary_uref: URef, dictionary_item_key: &str) -> Option<T> {
+    storage::dictionary_get(dictionary_uref, dictionary_item_key).unwrap_or_revert()
+}
+
+/// Creates a dictionary item key for the item `index` of `owner`, e.g. a lock position.
+pub(crate) fn make_indexed_item_key(owner: Address, index: u32) -> String {
+    let mut preimage = owner.to_bytes().unwrap_or_revert();
+    preimage.append(&mut index.to_bytes().unwrap_or_revert());
+    // About 37 bytes of preimage are encoded in ~52 characters, still within the 64 characters
+    // limit of dictionary item keys.
+    base64::encode(&preimage)
+}
+
+/// Name of the named key `name` of the reward token in `slot` of "reward_tokens".
+/// The first reward token keeps the plain names, the following ones get the slot as a suffix,
+/// e.g. "reward_rate", "reward_rate_1", "reward_rate_2".
+pub(crate) fn reward_key_name(name: &str, slot: usize) -> String {
+    if slot == 0 {
+        name.to_string()
+    } else {
+        format!("{}_{}", name, slot)
+    }
 }
\ No newline at end of file
@@ -1,5 +1,6 @@
 use casper_types::{U256, URef, contracts::NamedKeys, Key};
-use alloc::string::{String, ToString};
+use casper_erc20::Address;
+use alloc::{string::{String, ToString}, vec, vec::Vec};
 use casper_contract::{
     contract_api::{runtime, storage},
     unwrap_or_revert::UnwrapOrRevert
@@ -9,14 +10,41 @@ use crate::constants::{
     STAKE_TOKEN_HASH_KEY_NAME, REWARD_TOKEN_HASH_KEY_NAME, REWARD_RATE_KEY_NAME,
     LAST_UPDATE_KEY_NAME, REWARD_PER_TOKEN_STORED_KEY_NAME, TOTAL_SUPPLY_KEY_NAME,
     BALANCES_KEY_NAME, REWARDS_KEY_NAME, USER_REWARD_PER_TOKEN_PAID_KEY_NAME,
-    STAKING_CONTRACT_KEY_NAME
+    STAKING_CONTRACT_KEY_NAME, PERIOD_FINISH_KEY_NAME, OWNER_KEY_NAME,
+    PENDING_OWNER_KEY_NAME, PAUSED_KEY_NAME, REWARD_PER_TOKEN_DUST_KEY_NAME,
+    EVENTS_KEY_NAME, EVENT_COUNT_KEY_NAME, EFFECTIVE_SUPPLY_KEY_NAME, LOCK_TIERS_KEY_NAME,
+    EFFECTIVE_BALANCES_KEY_NAME, LOCKED_BALANCES_KEY_NAME, LOCK_POSITIONS_KEY_NAME,
+    LOCK_POSITION_COUNTS_KEY_NAME, REWARD_TOKENS_KEY_NAME, COMPOUNDING_ENABLED_KEY_NAME,
+    CONTRACT_VERSION_KEY_NAME, CONTRACT_VERSION, STAKE_TOKEN_DECIMALS_KEY_NAME,
+    REWARD_TOKEN_DECIMALS_KEY_NAME, REWARD_RESERVE_KEY_NAME, OUTSTANDING_REWARDS_KEY_NAME,
+    UNBONDING_PERIOD_KEY_NAME, UNBONDING_ENTRIES_KEY_NAME, PENALTY_PERIOD_KEY_NAME,
+    PENALTY_RATE_KEY_NAME, TREASURY_KEY_NAME, WEIGHTED_STAKE_TIMES_KEY_NAME, VESTING_PERIOD_KEY_NAME,
+    VESTING_ESCROWS_KEY_NAME, VESTED_REWARDS_KEY_NAME, BOOST_SUPPLY_KEY_NAME, WORKING_SUPPLY_KEY_NAME,
+    BOOST_BALANCES_KEY_NAME, WORKING_BALANCES_KEY_NAME, TOTAL_SUPPLY_CHECKPOINT_COUNT_KEY_NAME,
+    BALANCE_CHECKPOINTS_KEY_NAME, BALANCE_CHECKPOINT_COUNTS_KEY_NAME, TOTAL_SUPPLY_CHECKPOINTS_KEY_NAME,
+    ALLOWLIST_ENABLED_KEY_NAME, ALLOWLIST_KEY_NAME, MIN_STAKE_KEY_NAME, MAX_STAKE_PER_ADDRESS_KEY_NAME,
+    POOL_CAP_KEY_NAME, EMISSION_SCHEDULE_KEY_NAME, TOTAL_UNBONDING_KEY_NAME, ESCROWED_REWARDS_KEY_NAME
 };
+use crate::helpers::make_dictionary_item_key;
 
 pub fn default(
     staking_contract_name: String,
     stake_token_hash_key: Key,
     reward_token_hash_key: Key,
-    reward_rate: U256
+    owner: Address,
+    compounding_enabled: bool,
+    stake_token_decimals: u8,
+    reward_token_decimals: u8,
+    unbonding_period: U256,
+    penalty_period: U256,
+    penalty_rate: U256,
+    treasury: Option<Address>,
+    vesting_period: U256,
+    allowlist_enabled: bool,
+    allowlist: Vec<Address>,
+    min_stake: U256,
+    max_stake_per_address: U256,
+    pool_cap: U256
             ) -> NamedKeys {
     
     let mut named_keys = NamedKeys::new();
@@ -29,22 +57,25 @@ pub fn default(
         Key::from(staking_contract_name_uref)
     };
 
-    // 1. We need to pass 'Contract Hashes' of ERC20 'Stake'(WCSPR) and 'Reward'(PICAS) tokens, read only
+    // 1. We need to pass 'Contract Hashes' of ERC20 'Stake'(WCSPR) and 'Reward'(PICAS) tokens, read and write
     // We request to pass hash of a erc20 token's contracts as a Key value
+    // Owner is able to replace them later
     let stake_token_key = {
-        let stake_token_uref = storage::new_uref(stake_token_hash_key).into_read();
+        let stake_token_uref = storage::new_uref(stake_token_hash_key).into_read_write();
         Key::from(stake_token_uref)
     };
 
     let reward_token_key = {
-        let reward_token_uref: URef = storage::new_uref(reward_token_hash_key).into_read();
+        let reward_token_uref: URef = storage::new_uref(reward_token_hash_key).into_read_write();
         Key::from(reward_token_uref)
     };
 
-    // 2. "reward_rate", read only
+    // 2. "reward_rate", read and write
     // [R] in equasion
+    // Zero until the first reward period is started with 'notify_reward_amount'
+    let reward_rate: U256 = U256::from(0);
     let reward_rate_key = {
-        let reward_rate_uref = storage::new_uref(reward_rate).into_read();
+        let reward_rate_uref = storage::new_uref(reward_rate).into_read_write();
         Key::from(reward_rate_uref)
     };
 
@@ -59,12 +90,21 @@ pub fn default(
     // 4. "reward_per_token_stored", read and write
     // left side of equasion s = sum(t,0,b){ R / L(t) }
     // summation of reward rate devided by tottal supply of staked tokens at each given time
+    // Scaled by REWARD_PRECISION, so small rates over a large supply don't truncate to zero
     let reward_per_token_stored: U256 = U256::from(0);
     let reward_per_token_stored_key = {
         let reward_per_token_stored_uref = storage::new_uref(reward_per_token_stored).into_read_write();
         Key::from(reward_per_token_stored_uref)
     };
 
+    // "reward_per_token_dust", read and write
+    // Remainder of the last 'reward_per_token_stored' division, added to the next one
+    let reward_per_token_dust: U256 = U256::from(0);
+    let reward_per_token_dust_key = {
+        let reward_per_token_dust_uref = storage::new_uref(reward_per_token_dust).into_read_write();
+        Key::from(reward_per_token_dust_uref)
+    };
+
     // 5. Total supply, read and write
     // Total value of Staked WCSPR tokens in the contract
     let total_supply: U256 = U256::from(0);
@@ -73,18 +113,268 @@ pub fn default(
         Key::from(total_supply_uref)
     };
 
+    // 6. "period_finish", read and write
+    // End of the current reward period, rewards do not accrue past it
+    // No period is running at install, so it finishes right away
+    let period_finish: U256 = last_update_time;
+    let period_finish_key = {
+        let period_finish_uref = storage::new_uref(period_finish).into_read_write();
+        Key::from(period_finish_uref)
+    };
+
+    // 7. "owner", read and write
+    // Account allowed to call admin entry points, the installer by default
+    let owner_key = {
+        let owner_uref = storage::new_uref(owner).into_read_write();
+        Key::from(owner_uref)
+    };
+
+    // 8. "pending_owner", read and write
+    // Set by 'transfer_ownership' until the new owner accepts it
+    let pending_owner: Option<Address> = None;
+    let pending_owner_key = {
+        let pending_owner_uref = storage::new_uref(pending_owner).into_read_write();
+        Key::from(pending_owner_uref)
+    };
+
+    // 9. "paused", read and write
+    // Owner can stop staking and reward payouts, withdrawals keep working
+    let paused_key = {
+        let paused_uref = storage::new_uref(false).into_read_write();
+        Key::from(paused_uref)
+    };
+
+    // 10. "event_count", read and write
+    // Number of events in "events" dictionary, index of the next event
+    let event_count: u64 = 0;
+    let event_count_key = {
+        let event_count_uref = storage::new_uref(event_count).into_read_write();
+        Key::from(event_count_uref)
+    };
+
+    // 11. "effective_supply", read and write
+    // Sum of stakes weighted by their lock multipliers, rewards are shared by it
+    let effective_supply: U256 = U256::from(0);
+    let effective_supply_key = {
+        let effective_supply_uref = storage::new_uref(effective_supply).into_read_write();
+        Key::from(effective_supply_uref)
+    };
+
+    // 12. "lock_tiers", read and write
+    // (lock duration, multiplier in basis points) pairs set by the owner, none at install
+    let lock_tiers: Vec<(U256, U256)> = Vec::new();
+    let lock_tiers_key = {
+        let lock_tiers_uref = storage::new_uref(lock_tiers).into_read_write();
+        Key::from(lock_tiers_uref)
+    };
+
+    // 13. "reward_tokens", read and write
+    // Every token paid as reward, the Reward Token given at install comes first.
+    // Keys of the other ones are suffixed with their position, see `reward_key_name`
+    let reward_tokens: Vec<Key> = vec![reward_token_hash_key];
+    let reward_tokens_key = {
+        let reward_tokens_uref = storage::new_uref(reward_tokens).into_read_write();
+        Key::from(reward_tokens_uref)
+    };
+
+    // 14. "compounding_enabled", read and write
+    // Rewards can be restaked with 'compound' when the Stake Token is the Reward Token
+    let compounding_enabled_key = {
+        let compounding_enabled_uref = storage::new_uref(compounding_enabled).into_read_write();
+        Key::from(compounding_enabled_uref)
+    };
+
+    // 15. "contract_version", read and write
+    // Schema version of the stored state, updated by 'migrate' after an upgrade
+    let contract_version_key = {
+        let contract_version_uref = storage::new_uref(CONTRACT_VERSION).into_read_write();
+        Key::from(contract_version_uref)
+    };
+
+    // 16. "stake_token_decimals" and "reward_token_decimals", read and write
+    // Read from the token contracts at install, updated when the tokens are replaced
+    let stake_token_decimals_key = {
+        let stake_token_decimals_uref = storage::new_uref(stake_token_decimals).into_read_write();
+        Key::from(stake_token_decimals_uref)
+    };
+
+    let reward_token_decimals_key = {
+        let reward_token_decimals_uref = storage::new_uref(reward_token_decimals).into_read_write();
+        Key::from(reward_token_decimals_uref)
+    };
+
+    // 17. "reward_reserve", read and write
+    // Reward Tokens held by the contract to pay rewards, funded by 'notify_reward_amount'
+    // and 'fund_rewards'. Tokens sent to the contract directly are not part of it
+    let reward_reserve: U256 = U256::from(0);
+    let reward_reserve_key = {
+        let reward_reserve_uref = storage::new_uref(reward_reserve).into_read_write();
+        Key::from(reward_reserve_uref)
+    };
+
+    // 18. "outstanding_rewards", read and write
+    // Rewards promised and not paid yet: credited to stakers or still to distribute
+    // in the running period. Never higher than "reward_reserve" when promised
+    let outstanding_rewards: U256 = U256::from(0);
+    let outstanding_rewards_key = {
+        let outstanding_rewards_uref = storage::new_uref(outstanding_rewards).into_read_write();
+        Key::from(outstanding_rewards_uref)
+    };
+
+    // 19. "unbonding_period", read
+    // Time withdrawn stake waits in the unbonding queue, set at install. Zero withdraws right away
+    let unbonding_period_key = {
+        let unbonding_period_uref = storage::new_uref(unbonding_period).into_read();
+        Key::from(unbonding_period_uref)
+    };
+
+    // 20. "penalty_period", "penalty_rate" and "treasury", read
+    // Withdrawing within "penalty_period" of the last stake costs "penalty_rate" basis points
+    // of the amount. The fee goes to "treasury", or to the stakers left when there is none
+    let penalty_period_key = {
+        let penalty_period_uref = storage::new_uref(penalty_period).into_read();
+        Key::from(penalty_period_uref)
+    };
+
+    let penalty_rate_key = {
+        let penalty_rate_uref = storage::new_uref(penalty_rate).into_read();
+        Key::from(penalty_rate_uref)
+    };
+
+    let treasury_key = {
+        let treasury_uref = storage::new_uref(treasury).into_read();
+        Key::from(treasury_uref)
+    };
+
+    // 21. "vesting_period", read
+    // Claimed rewards unlock linearly over it from an escrow, zero pays them right away
+    let vesting_period_key = {
+        let vesting_period_uref = storage::new_uref(vesting_period).into_read();
+        Key::from(vesting_period_uref)
+    };
+
+    // 22. "boost_supply", read and write
+    // Boost Tokens locked by all the users. "boost_token_hash" is only added by 'set_boost_token',
+    // until then working balances are the effective balances
+    let boost_supply: U256 = U256::from(0);
+    let boost_supply_key = {
+        let boost_supply_uref = storage::new_uref(boost_supply).into_read_write();
+        Key::from(boost_supply_uref)
+    };
+
+    // 23. "working_supply", read and write
+    // Sum of the working balances, rewards are shared over it
+    let working_supply: U256 = U256::from(0);
+    let working_supply_key = {
+        let working_supply_uref = storage::new_uref(working_supply).into_read_write();
+        Key::from(working_supply_uref)
+    };
+
+    // 24. "total_supply_checkpoint_count", read and write
+    // Number of entries in "total_supply_checkpoints"
+    let total_supply_checkpoint_count: u32 = 0;
+    let total_supply_checkpoint_count_key = {
+        let total_supply_checkpoint_count_uref = storage::new_uref(total_supply_checkpoint_count).into_read_write();
+        Key::from(total_supply_checkpoint_count_uref)
+    };
+
+    // 25. "allowlist_enabled", read and write
+    // Only addresses of "allowlist" can stake while it is set
+    let allowlist_enabled_key = {
+        let allowlist_enabled_uref = storage::new_uref(allowlist_enabled).into_read_write();
+        Key::from(allowlist_enabled_uref)
+    };
+
+    // 26. "min_stake", read and write
+    // Smallest amount a single stake can deposit, 0 allows any amount
+    let min_stake_key = {
+        let min_stake_uref = storage::new_uref(min_stake).into_read_write();
+        Key::from(min_stake_uref)
+    };
+
+    // 27. "max_stake_per_address", read and write
+    // Highest balance a staker can reach by staking, 0 is unlimited
+    let max_stake_per_address_key = {
+        let max_stake_per_address_uref = storage::new_uref(max_stake_per_address).into_read_write();
+        Key::from(max_stake_per_address_uref)
+    };
+
+    // 28. "pool_cap", read and write
+    // Highest "total_supply" staking can reach, 0 is unlimited
+    let pool_cap_key = {
+        let pool_cap_uref = storage::new_uref(pool_cap).into_read_write();
+        Key::from(pool_cap_uref)
+    };
+
+    // 29. "emission_schedule", read and write
+    // (start time, reward rate) segments replacing "reward_rate" while not empty,
+    // one per reward token like "reward_rate"
+    let emission_schedule: Vec<(U256, U256)> = Vec::new();
+    let emission_schedule_key = {
+        let emission_schedule_uref = storage::new_uref(emission_schedule).into_read_write();
+        Key::from(emission_schedule_uref)
+    };
+
+    // 30. "total_unbonding", read and write
+    // Stake queued by 'request_withdraw' of all the users and not claimed yet, it is owed
+    // in the Stake Token though it has left "total_supply"
+    let total_unbonding: U256 = U256::from(0);
+    let total_unbonding_key = {
+        let total_unbonding_uref = storage::new_uref(total_unbonding).into_read_write();
+        Key::from(total_unbonding_uref)
+    };
+
+    // 31. "escrowed_rewards", read and write
+    // Rewards taken out of the reserve into the vesting escrows and not claimed yet,
+    // one per reward token like "reward_reserve"
+    let escrowed_rewards: U256 = U256::from(0);
+    let escrowed_rewards_key = {
+        let escrowed_rewards_uref = storage::new_uref(escrowed_rewards).into_read_write();
+        Key::from(escrowed_rewards_uref)
+    };
+
     named_keys.insert(STAKING_CONTRACT_KEY_NAME.to_string(), staking_contract_name_key);
     named_keys.insert(STAKE_TOKEN_HASH_KEY_NAME.to_string(), stake_token_key);
     named_keys.insert(REWARD_TOKEN_HASH_KEY_NAME.to_string(), reward_token_key);
     named_keys.insert(REWARD_RATE_KEY_NAME.to_string(), reward_rate_key);
     named_keys.insert(LAST_UPDATE_KEY_NAME.to_string(), last_update_time_key);
     named_keys.insert(REWARD_PER_TOKEN_STORED_KEY_NAME.to_string(), reward_per_token_stored_key);
+    named_keys.insert(REWARD_PER_TOKEN_DUST_KEY_NAME.to_string(), reward_per_token_dust_key);
     named_keys.insert(TOTAL_SUPPLY_KEY_NAME.to_string(), total_supply_key);
+    named_keys.insert(PERIOD_FINISH_KEY_NAME.to_string(), period_finish_key);
+    named_keys.insert(OWNER_KEY_NAME.to_string(), owner_key);
+    named_keys.insert(PENDING_OWNER_KEY_NAME.to_string(), pending_owner_key);
+    named_keys.insert(PAUSED_KEY_NAME.to_string(), paused_key);
+    named_keys.insert(EVENT_COUNT_KEY_NAME.to_string(), event_count_key);
+    named_keys.insert(EFFECTIVE_SUPPLY_KEY_NAME.to_string(), effective_supply_key);
+    named_keys.insert(LOCK_TIERS_KEY_NAME.to_string(), lock_tiers_key);
+    named_keys.insert(REWARD_TOKENS_KEY_NAME.to_string(), reward_tokens_key);
+    named_keys.insert(COMPOUNDING_ENABLED_KEY_NAME.to_string(), compounding_enabled_key);
+    named_keys.insert(CONTRACT_VERSION_KEY_NAME.to_string(), contract_version_key);
+    named_keys.insert(STAKE_TOKEN_DECIMALS_KEY_NAME.to_string(), stake_token_decimals_key);
+    named_keys.insert(REWARD_TOKEN_DECIMALS_KEY_NAME.to_string(), reward_token_decimals_key);
+    named_keys.insert(REWARD_RESERVE_KEY_NAME.to_string(), reward_reserve_key);
+    named_keys.insert(OUTSTANDING_REWARDS_KEY_NAME.to_string(), outstanding_rewards_key);
+    named_keys.insert(UNBONDING_PERIOD_KEY_NAME.to_string(), unbonding_period_key);
+    named_keys.insert(PENALTY_PERIOD_KEY_NAME.to_string(), penalty_period_key);
+    named_keys.insert(PENALTY_RATE_KEY_NAME.to_string(), penalty_rate_key);
+    named_keys.insert(TREASURY_KEY_NAME.to_string(), treasury_key);
+    named_keys.insert(VESTING_PERIOD_KEY_NAME.to_string(), vesting_period_key);
+    named_keys.insert(BOOST_SUPPLY_KEY_NAME.to_string(), boost_supply_key);
+    named_keys.insert(WORKING_SUPPLY_KEY_NAME.to_string(), working_supply_key);
+    named_keys.insert(TOTAL_SUPPLY_CHECKPOINT_COUNT_KEY_NAME.to_string(), total_supply_checkpoint_count_key);
+    named_keys.insert(ALLOWLIST_ENABLED_KEY_NAME.to_string(), allowlist_enabled_key);
+    named_keys.insert(MIN_STAKE_KEY_NAME.to_string(), min_stake_key);
+    named_keys.insert(MAX_STAKE_PER_ADDRESS_KEY_NAME.to_string(), max_stake_per_address_key);
+    named_keys.insert(POOL_CAP_KEY_NAME.to_string(), pool_cap_key);
+    named_keys.insert(EMISSION_SCHEDULE_KEY_NAME.to_string(), emission_schedule_key);
+    named_keys.insert(TOTAL_UNBONDING_KEY_NAME.to_string(), total_unbonding_key);
+    named_keys.insert(ESCROWED_REWARDS_KEY_NAME.to_string(), escrowed_rewards_key);
 
     // Contract 'Dictionaries'
 
     // 1. "user_reward_per_token_paid"
-    // Right side of equasion p = sum(t,0,a-1){ R / L(t)}
+    // Right side of equasion p = sum(t,0,a-1){ R / L(t)}, scaled by REWARD_PRECISION
     let user_reward_per_token_paid_uref = storage::new_dictionary(USER_REWARD_PER_TOKEN_PAID_KEY_NAME).unwrap_or_revert();
     let user_reward_per_token_paid_key = {
         Key::from(user_reward_per_token_paid_uref)
@@ -105,9 +395,133 @@ pub fn default(
         Key::from(balances_dictionary_uref)
     };
 
+    // 4. "events"
+    // Append-only log of contract events, see `events` module for the format
+    let events_dictionary_uref: URef = storage::new_dictionary(EVENTS_KEY_NAME).unwrap_or_revert();
+    let events_dictionary_key = {
+        Key::from(events_dictionary_uref)
+    };
+
+    // 5. "effective_balances"
+    // Stake of users weighted by lock multipliers, unlocked stake counts 1x
+    let effective_balances_dictionary_uref: URef = storage::new_dictionary(EFFECTIVE_BALANCES_KEY_NAME).unwrap_or_revert();
+    let effective_balances_dictionary_key = {
+        Key::from(effective_balances_dictionary_uref)
+    };
+
+    // 6. "locked_balances"
+    // Part of the stake of users held in lock positions
+    let locked_balances_dictionary_uref: URef = storage::new_dictionary(LOCKED_BALANCES_KEY_NAME).unwrap_or_revert();
+    let locked_balances_dictionary_key = {
+        Key::from(locked_balances_dictionary_uref)
+    };
+
+    // 7. "lock_positions"
+    // (amount, unlock time, effective amount) of every lock position of a user
+    let lock_positions_dictionary_uref: URef = storage::new_dictionary(LOCK_POSITIONS_KEY_NAME).unwrap_or_revert();
+    let lock_positions_dictionary_key = {
+        Key::from(lock_positions_dictionary_uref)
+    };
+
+    // 8. "lock_position_counts"
+    // Number of lock positions opened by a user, id of the next one
+    let lock_position_counts_dictionary_uref: URef = storage::new_dictionary(LOCK_POSITION_COUNTS_KEY_NAME).unwrap_or_revert();
+    let lock_position_counts_dictionary_key = {
+        Key::from(lock_position_counts_dictionary_uref)
+    };
+
+    // 9. "unbonding_entries"
+    // (amount, unlock time) of the withdrawn stake of a user waiting for 'claim_unbonded'
+    let unbonding_entries_dictionary_uref: URef = storage::new_dictionary(UNBONDING_ENTRIES_KEY_NAME).unwrap_or_revert();
+    let unbonding_entries_dictionary_key = {
+        Key::from(unbonding_entries_dictionary_uref)
+    };
+
+    // 10. "weighted_stake_times"
+    // Block times of the stakes of a user averaged by amount, starts its early-exit penalty period
+    let weighted_stake_times_dictionary_uref: URef = storage::new_dictionary(WEIGHTED_STAKE_TIMES_KEY_NAME).unwrap_or_revert();
+    let weighted_stake_times_dictionary_key = {
+        Key::from(weighted_stake_times_dictionary_uref)
+    };
+
+    // 11. "vesting_escrows"
+    // (locked amount, vesting start, vesting end) of the claimed rewards of a user,
+    // suffixed like "rewards" for the other reward tokens
+    let vesting_escrows_dictionary_uref: URef = storage::new_dictionary(VESTING_ESCROWS_KEY_NAME).unwrap_or_revert();
+    let vesting_escrows_dictionary_key = {
+        Key::from(vesting_escrows_dictionary_uref)
+    };
+
+    // 12. "vested_rewards"
+    // Escrowed rewards of a user already unlocked and not claimed yet
+    let vested_rewards_dictionary_uref: URef = storage::new_dictionary(VESTED_REWARDS_KEY_NAME).unwrap_or_revert();
+    let vested_rewards_dictionary_key = {
+        Key::from(vested_rewards_dictionary_uref)
+    };
+
+    // 13. "boost_balances"
+    // Boost Tokens locked by a user
+    let boost_balances_dictionary_uref: URef = storage::new_dictionary(BOOST_BALANCES_KEY_NAME).unwrap_or_revert();
+    let boost_balances_dictionary_key = {
+        Key::from(boost_balances_dictionary_uref)
+    };
+
+    // 14. "working_balances"
+    // Part of the effective balance of a user earning rewards, depends on its Boost Tokens
+    let working_balances_dictionary_uref: URef = storage::new_dictionary(WORKING_BALANCES_KEY_NAME).unwrap_or_revert();
+    let working_balances_dictionary_key = {
+        Key::from(working_balances_dictionary_uref)
+    };
+
+    // 15. "balance_checkpoints"
+    // (block time, balance) of a user after every change of its stake, in time order
+    let balance_checkpoints_dictionary_uref: URef = storage::new_dictionary(BALANCE_CHECKPOINTS_KEY_NAME).unwrap_or_revert();
+    let balance_checkpoints_dictionary_key = {
+        Key::from(balance_checkpoints_dictionary_uref)
+    };
+
+    // 16. "balance_checkpoint_counts"
+    // Number of balance checkpoints of a user
+    let balance_checkpoint_counts_dictionary_uref: URef = storage::new_dictionary(BALANCE_CHECKPOINT_COUNTS_KEY_NAME).unwrap_or_revert();
+    let balance_checkpoint_counts_dictionary_key = {
+        Key::from(balance_checkpoint_counts_dictionary_uref)
+    };
+
+    // 17. "total_supply_checkpoints"
+    // (block time, total supply) after every change of "total_supply", keyed by index like "events"
+    let total_supply_checkpoints_dictionary_uref: URef = storage::new_dictionary(TOTAL_SUPPLY_CHECKPOINTS_KEY_NAME).unwrap_or_revert();
+    let total_supply_checkpoints_dictionary_key = {
+        Key::from(total_supply_checkpoints_dictionary_uref)
+    };
+
+    // 18. "allowlist"
+    // Whether an address may stake while "allowlist_enabled" is set, filled with the install list
+    let allowlist_dictionary_uref: URef = storage::new_dictionary(ALLOWLIST_KEY_NAME).unwrap_or_revert();
+    for address in allowlist {
+        storage::dictionary_put(allowlist_dictionary_uref, &make_dictionary_item_key(address), true);
+    }
+    let allowlist_dictionary_key = {
+        Key::from(allowlist_dictionary_uref)
+    };
+
     named_keys.insert(USER_REWARD_PER_TOKEN_PAID_KEY_NAME.to_string(), user_reward_per_token_paid_key);
     named_keys.insert(REWARDS_KEY_NAME.to_string(), rewards_dictionary_key);
     named_keys.insert(BALANCES_KEY_NAME.to_string(), balances_dictionary_key);
+    named_keys.insert(EVENTS_KEY_NAME.to_string(), events_dictionary_key);
+    named_keys.insert(EFFECTIVE_BALANCES_KEY_NAME.to_string(), effective_balances_dictionary_key);
+    named_keys.insert(LOCKED_BALANCES_KEY_NAME.to_string(), locked_balances_dictionary_key);
+    named_keys.insert(LOCK_POSITIONS_KEY_NAME.to_string(), lock_positions_dictionary_key);
+    named_keys.insert(LOCK_POSITION_COUNTS_KEY_NAME.to_string(), lock_position_counts_dictionary_key);
+    named_keys.insert(UNBONDING_ENTRIES_KEY_NAME.to_string(), unbonding_entries_dictionary_key);
+    named_keys.insert(WEIGHTED_STAKE_TIMES_KEY_NAME.to_string(), weighted_stake_times_dictionary_key);
+    named_keys.insert(VESTING_ESCROWS_KEY_NAME.to_string(), vesting_escrows_dictionary_key);
+    named_keys.insert(VESTED_REWARDS_KEY_NAME.to_string(), vested_rewards_dictionary_key);
+    named_keys.insert(BOOST_BALANCES_KEY_NAME.to_string(), boost_balances_dictionary_key);
+    named_keys.insert(WORKING_BALANCES_KEY_NAME.to_string(), working_balances_dictionary_key);
+    named_keys.insert(BALANCE_CHECKPOINTS_KEY_NAME.to_string(), balance_checkpoints_dictionary_key);
+    named_keys.insert(BALANCE_CHECKPOINT_COUNTS_KEY_NAME.to_string(), balance_checkpoint_counts_dictionary_key);
+    named_keys.insert(TOTAL_SUPPLY_CHECKPOINTS_KEY_NAME.to_string(), total_supply_checkpoints_dictionary_key);
+    named_keys.insert(ALLOWLIST_KEY_NAME.to_string(), allowlist_dictionary_key);
     
     named_keys
 }
\ No newline at end of file
@@ -9,218 +9,2425 @@ extern crate alloc;
 mod helpers;
 mod entry_points;
 mod named_keys;
+mod error;
+mod events;
 pub mod constants;
 
-use crate::helpers::{ set_key, get_key, get_immediate_caller_address, dictionary_read, dictionary_write, get_self_address};
+use crate::helpers::{
+    set_key, get_key, get_immediate_caller_address, dictionary_read, dictionary_write, get_self_address,
+    dictionary_get, make_dictionary_item_key, make_indexed_item_key, reward_key_name
+};
+
+use crate::constants::{
+    STAKING_CONTRACT_KEY_NAME, REWARD_TOKEN_HASH_KEY_NAME, STAKE_TOKEN_HASH_KEY_NAME,
+    REWARD_RATE_KEY_NAME, TOTAL_SUPPLY_KEY_NAME, AMOUNT_KEY_NAME, BALANCES_KEY_NAME,
+    REWARDS_KEY_NAME, USER_REWARD_PER_TOKEN_PAID_KEY_NAME, LAST_UPDATE_KEY_NAME,
+    REWARD_PER_TOKEN_STORED_KEY_NAME, PERIOD_FINISH_KEY_NAME, DURATION_KEY_NAME,
+    OWNER_KEY_NAME, PENDING_OWNER_KEY_NAME, NEW_OWNER_KEY_NAME, PAUSED_KEY_NAME,
+    REWARD_PER_TOKEN_DUST_KEY_NAME, REWARD_PRECISION, ADDRESS_KEY_NAME, MULTIPLIER_BASE,
+    EFFECTIVE_SUPPLY_KEY_NAME, EFFECTIVE_BALANCES_KEY_NAME, LOCKED_BALANCES_KEY_NAME,
+    LOCK_POSITIONS_KEY_NAME, LOCK_POSITION_COUNTS_KEY_NAME, LOCK_TIERS_KEY_NAME,
+    LOCK_DURATION_KEY_NAME, POSITION_ID_KEY_NAME, REWARD_TOKENS_KEY_NAME, REWARD_TOKEN_KEY_NAME,
+    MAX_REWARD_TOKENS, COMPOUNDING_ENABLED_KEY_NAME, BENEFICIARY_KEY_NAME, RECIPIENT_KEY_NAME,
+    CONTRACT_VERSION, CONTRACT_VERSION_KEY_NAME, MIGRATE_ENTRY_POINT_NAME,
+    STAKE_TOKEN_DECIMALS_KEY_NAME, REWARD_TOKEN_DECIMALS_KEY_NAME, REWARD_RESERVE_KEY_NAME,
+    OUTSTANDING_REWARDS_KEY_NAME, UNBONDING_PERIOD_KEY_NAME, UNBONDING_ENTRIES_KEY_NAME,
+    PENALTY_RATE_BASE, PENALTY_PERIOD_KEY_NAME, PENALTY_RATE_KEY_NAME, TREASURY_KEY_NAME,
+    WEIGHTED_STAKE_TIMES_KEY_NAME, VESTING_PERIOD_KEY_NAME, VESTING_ESCROWS_KEY_NAME, VESTED_REWARDS_KEY_NAME,
+    UNBOOSTED_SHARE, BOOST_SHARE_BASE, BOOST_TOKEN_HASH_KEY_NAME, BOOST_SUPPLY_KEY_NAME, WORKING_SUPPLY_KEY_NAME,
+    BOOST_BALANCES_KEY_NAME, WORKING_BALANCES_KEY_NAME, TOTAL_SUPPLY_CHECKPOINT_COUNT_KEY_NAME,
+    BALANCE_CHECKPOINTS_KEY_NAME, BALANCE_CHECKPOINT_COUNTS_KEY_NAME, TOTAL_SUPPLY_CHECKPOINTS_KEY_NAME,
+    TIMESTAMP_KEY_NAME, OWNER_ENTRY_POINT_NAME, ALLOWLIST_ENABLED_KEY_NAME, ALLOWLIST_KEY_NAME,
+    ADDRESSES_KEY_NAME, MIN_STAKE_KEY_NAME, MAX_STAKE_PER_ADDRESS_KEY_NAME, POOL_CAP_KEY_NAME,
+    EMISSION_SCHEDULE_KEY_NAME, MAX_EMISSION_SEGMENTS, TOKEN_KEY_NAME, TOTAL_UNBONDING_KEY_NAME,
+    ESCROWED_REWARDS_KEY_NAME,
+};
+
+use crate::error::StakingError;
+use crate::events::{emit, StakingEvent};
+
+use alloc::{string::{String, ToString}, vec::Vec};
+
+use casper_erc20::{ Address,
+    constants::{
+        TRANSFER_ENTRY_POINT_NAME, TRANSFER_FROM_ENTRY_POINT_NAME, BALANCE_OF_ENTRY_POINT_NAME,
+        ALLOWANCE_ENTRY_POINT_NAME, OWNER_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME,
+        AMOUNT_RUNTIME_ARG_NAME, ADDRESS_RUNTIME_ARG_NAME, SPENDER_RUNTIME_ARG_NAME,
+        DECIMALS_ENTRY_POINT_NAME}
+    };
+
+use casper_contract::{contract_api::{runtime, storage}, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    contracts::{NamedKeys}, U256, ContractHash, ContractPackageHash, Key, URef, RuntimeArgs, runtime_args,
+    HashAddr, CLValue};
+
+#[no_mangle]
+fn call() {
+    
+    let contract_name: String = runtime::get_named_arg(STAKING_CONTRACT_KEY_NAME);
+    let contract_hash_key_name = String::from(contract_name.clone());
+    let contract_package_hash_key_name = String::from(contract_name.clone() + "_package_hash");
+    let contract_access_uref_key_name = String::from(contract_name.clone() + "_package_access");
+
+    // Running the session again from the installing account upgrades the contract
+    if runtime::has_key(&contract_package_hash_key_name) {
+        upgrade(contract_hash_key_name, contract_package_hash_key_name);
+        return;
+    }
+    
+    let stake_token_key: Key = runtime::get_named_arg(STAKE_TOKEN_HASH_KEY_NAME);
+    let reward_token_key: Key = runtime::get_named_arg(REWARD_TOKEN_HASH_KEY_NAME);
+
+    // Installer becomes the owner of the contract
+    let owner: Address = Address::from(runtime::get_caller());
+
+    // Wrong token keys fail the install instead of the first stake
+    let stake_token_decimals: u8 = erc20_decimals(stake_token_key);
+    let reward_token_decimals: u8 = erc20_decimals(reward_token_key);
+
+    let unbonding_period: U256 = runtime::get_named_arg(UNBONDING_PERIOD_KEY_NAME);
+
+    let penalty_period: U256 = runtime::get_named_arg(PENALTY_PERIOD_KEY_NAME);
+    let penalty_rate: U256 = runtime::get_named_arg(PENALTY_RATE_KEY_NAME);
+    let treasury: Option<Address> = runtime::get_named_arg(TREASURY_KEY_NAME);
+
+    let vesting_period: U256 = runtime::get_named_arg(VESTING_PERIOD_KEY_NAME);
+
+    // Rewards of a pool staking its own Reward Token can be restaked in place,
+    // unless they have to vest first
+    let compounding_enabled: bool = stake_token_key == reward_token_key && vesting_period.is_zero();
+
+    // Partner pools start with their list of stakers
+    let allowlist_enabled: bool = runtime::get_named_arg(ALLOWLIST_ENABLED_KEY_NAME);
+    let allowlist: Vec<Address> = runtime::get_named_arg(ALLOWLIST_KEY_NAME);
+
+    let min_stake: U256 = runtime::get_named_arg(MIN_STAKE_KEY_NAME);
+    let max_stake_per_address: U256 = runtime::get_named_arg(MAX_STAKE_PER_ADDRESS_KEY_NAME);
+    let pool_cap: U256 = runtime::get_named_arg(POOL_CAP_KEY_NAME);
+
+    if penalty_rate > U256::from(PENALTY_RATE_BASE) {
+        runtime::revert(StakingError::InvalidPenaltyRate);
+    }
+
+    // a fee that is not sent to a treasury is shared through the reward slot of the Stake Token
+    if !penalty_rate.is_zero() && treasury.is_none() && stake_token_key != reward_token_key {
+        runtime::revert(StakingError::PenaltyNotPayable);
+    }
+
+    let named_keys: NamedKeys = named_keys::default(
+        contract_name,
+        stake_token_key,
+        reward_token_key,
+        owner,
+        compounding_enabled,
+        stake_token_decimals,
+        reward_token_decimals,
+        unbonding_period,
+        penalty_period,
+        penalty_rate,
+        treasury,
+        vesting_period,
+        allowlist_enabled,
+        allowlist,
+        min_stake,
+        max_stake_per_address,
+        pool_cap
+    );
+    
+    // We store contract on-chain, the access URef stays in the installing account
+    // so it is the only one able to add new versions
+    let (contract_hash, _version) = storage::new_contract(
+        entry_points::default(),
+        Some(named_keys),
+        Some(String::from(contract_package_hash_key_name)),
+        Some(contract_access_uref_key_name)
+    );
+
+    runtime::put_key(contract_hash_key_name.as_str(), Key::from(contract_hash));
+}
+
+/// Adds a new version of the contract to the package installed by the caller.
+/// Named keys of the previous version, dictionaries included, are carried over to the new one,
+/// which replaces the previous version. It is migrated right away when the installer still owns
+/// the contract, otherwise the owner, a governance contract for instance, calls 'migrate' itself
+fn upgrade(contract_hash_key_name: String, contract_package_hash_key_name: String) {
+
+    let contract_package_hash: ContractPackageHash = runtime::get_key(&contract_package_hash_key_name)
+        .unwrap_or_revert()
+        .into_hash()
+        .unwrap_or_revert()
+        .into();
+    let previous_contract_hash: ContractHash = runtime::get_key(&contract_hash_key_name)
+        .unwrap_or_revert()
+        .into_hash()
+        .unwrap_or_revert()
+        .into();
+
+    let (contract_hash, _version) = storage::add_contract_version(
+        contract_package_hash,
+        entry_points::default(),
+        NamedKeys::new()
+    );
+
+    // Stakers can't keep calling the code being replaced
+    storage::disable_contract_version(contract_package_hash, previous_contract_hash).unwrap_or_revert();
+
+    runtime::put_key(contract_hash_key_name.as_str(), Key::from(contract_hash));
+
+    let owner: Address = runtime::call_contract(contract_hash, OWNER_ENTRY_POINT_NAME, runtime_args!{});
+
+    if owner == Address::from(runtime::get_caller()) {
+        let _: () = runtime::call_contract(contract_hash, MIGRATE_ENTRY_POINT_NAME, runtime_args!{});
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn stake() {
+    
+    // TODO Frontend should check 'allowance' of ERC20 'Stake token' contract for user
+    // Let user to call 'approve' first, before staking
+
+    let staker: Address = get_immediate_caller_address().unwrap_or_revert();
+    let amount: U256 = runtime::get_named_arg(AMOUNT_KEY_NAME);
+
+    stake_tokens(staker, staker, amount);
+}
+
+/// Stakes for `beneficiary`, the Stake Token is pulled from the caller.
+/// Lets vesting or launchpad contracts deposit on behalf of their users
+#[no_mangle]
+pub extern "C" fn stake_for() {
+
+    let payer: Address = get_immediate_caller_address().unwrap_or_revert();
+    let beneficiary: Address = runtime::get_named_arg(BENEFICIARY_KEY_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_KEY_NAME);
+
+    stake_tokens(beneficiary, payer, amount);
+}
+
+/// Credits `amount` to `staker`, the Stake Token is pulled from `payer`
+fn stake_tokens(staker: Address, payer: Address, amount: U256) {
+
+    when_not_paused();
+    when_allowlisted(staker);
+
+    if amount.is_zero() {
+        runtime::revert(StakingError::ZeroAmount);
+    }
+
+    when_within_stake_limits(staker, amount);
+
+    let balances_key: Key = runtime::get_key(BALANCES_KEY_NAME).unwrap_or_revert();
+    let balances_uref: URef = balances_key.into_uref().unwrap_or_revert();
+    
+    update_reward(staker);
+    
+    // update total_supply
+    named_key_add(amount, TOTAL_SUPPLY_KEY_NAME);
+
+    // update balance of staker
+    dictionary_add(balances_uref, staker, amount).unwrap_or_revert();
+    checkpoint_balance(staker);
+
+    // stake that is not locked earns rewards 1x
+    increase_effective_balance(staker, amount);
+
+    record_stake_time(staker, amount);
+
+    // Transfer `amount` of Stake Token from payer to the stake contract
+    erc20_transfer_from(
+        STAKE_TOKEN_HASH_KEY_NAME,
+        payer,
+        amount
+    );
+
+    emit(StakingEvent::Staked { staker, amount });
+
+}
+
+#[no_mangle]
+pub extern "C" fn withdraw() {
+    
+    let staker = get_immediate_caller_address().unwrap_or_revert();
+    let amount: U256 = runtime::get_named_arg(AMOUNT_KEY_NAME);
+
+    withdraw_tokens(staker, staker, amount);
+}
+
+/// Withdraws the stake of the caller and pays it, with the rewards, to `recipient`
+#[no_mangle]
+pub extern "C" fn withdraw_to() {
+
+    let staker = get_immediate_caller_address().unwrap_or_revert();
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_KEY_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_KEY_NAME);
+
+    when_recipient_paid_directly(staker, recipient);
+
+    withdraw_tokens(staker, recipient, amount);
+}
+
+/// Debits `amount` from `staker`, the Stake Token and the rewards go to `recipient`
+fn withdraw_tokens(staker: Address, recipient: Address, amount: U256) {
+
+    // withdrawn stake has to wait in the unbonding queue first
+    let unbonding_period: U256 = get_key(UNBONDING_PERIOD_KEY_NAME).unwrap_or_revert();
+    if !unbonding_period.is_zero() {
+        runtime::revert(StakingError::UnbondingRequired);
+    }
+
+    debit_stake(staker, amount);
+
+    let amount: U256 = charge_penalty(staker, amount);
+
+    // Transfer `amount` of Stake Token from the stake contract to recipient
+    erc20_transfer(
+        STAKE_TOKEN_HASH_KEY_NAME,
+        recipient,
+        amount
+    );
+
+    emit(StakingEvent::Withdrawn { staker, amount });
+
+    // Rewards stay credited to the staker while the contract is paused,
+    // so principal can always be withdrawn
+    if !is_paused() {
+        for slot in 0..reward_token_count() {
+            pay_reward(staker, recipient, slot);
+        }
+    }
+
+}
+
+/// Stops rewards on `amount` of the stake of the caller right away and queues it
+/// until the unbonding period is over, see `claim_unbonded`
+#[no_mangle]
+pub extern "C" fn request_withdraw() {
+
+    let staker: Address = get_immediate_caller_address().unwrap_or_revert();
+    let amount: U256 = runtime::get_named_arg(AMOUNT_KEY_NAME);
+
+    debit_stake(staker, amount);
+
+    let amount: U256 = charge_penalty(staker, amount);
+
+    queue_unbonding(staker, amount);
+
+    emit(StakingEvent::WithdrawRequested { staker, amount });
+
+    // Rewards stay credited to the staker while the contract is paused
+    if !is_paused() {
+        for slot in 0..reward_token_count() {
+            pay_reward(staker, staker, slot);
+        }
+    }
+}
+
+/// Transfers to the caller every unbonding entry whose unlock time has passed,
+/// the other ones stay in the queue
+#[no_mangle]
+pub extern "C" fn claim_unbonded() {
+
+    let staker: Address = get_immediate_caller_address().unwrap_or_revert();
+    let unbonding_entries_key: Key = runtime::get_key(UNBONDING_ENTRIES_KEY_NAME).unwrap_or_revert();
+    let unbonding_entries_uref: URef = unbonding_entries_key.into_uref().unwrap_or_revert();
+    let unbonding_entries_item_key: String = make_dictionary_item_key(staker);
+
+    let unbonding_entries: Vec<(U256, U256)> = dictionary_get(unbonding_entries_uref, &unbonding_entries_item_key).unwrap_or_default();
+
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+    let (matured, pending): (Vec<(U256, U256)>, Vec<(U256, U256)>) = unbonding_entries
+        .into_iter()
+        .partition(|(_, unlock_time)| *unlock_time <= current_block_time);
+
+    let amount: U256 = matured
+        .iter()
+        .fold(U256::zero(), |total, (amount, _)| total + *amount);
+
+    if amount.is_zero() {
+        runtime::revert(StakingError::NothingToClaim);
+    }
+
+    storage::dictionary_put(unbonding_entries_uref, &unbonding_entries_item_key, pending);
+    named_key_sub(amount, TOTAL_UNBONDING_KEY_NAME);
+
+    // Transfer the matured stake from the stake contract to caller
+    erc20_transfer(
+        STAKE_TOKEN_HASH_KEY_NAME,
+        staker,
+        amount
+    );
+
+    emit(StakingEvent::Withdrawn { staker, amount });
+}
+
+/// Removes `amount` from the stake of `staker` after checkpointing its rewards
+fn debit_stake(staker: Address, amount: U256) {
+
+    if amount.is_zero() {
+        runtime::revert(StakingError::ZeroAmount);
+    }
+
+    let balances_key: Key = runtime::get_key(BALANCES_KEY_NAME).unwrap_or_revert();
+    let balances_uref: URef = balances_key.into_uref().unwrap_or_revert();
+
+    update_reward(staker);
+
+    // update total_supply
+    named_key_sub(amount, TOTAL_SUPPLY_KEY_NAME);
+
+    // update balance of staker
+    dictionary_sub(balances_uref, staker, amount).unwrap_or_revert();
+    checkpoint_balance(staker);
+
+    // stake held in lock positions can't be withdrawn before the positions are unlocked
+    let locked_balances_key: Key = runtime::get_key(LOCKED_BALANCES_KEY_NAME).unwrap_or_revert();
+    let locked_balances_uref: URef = locked_balances_key.into_uref().unwrap_or_revert();
+    if dictionary_read(balances_uref, staker) < dictionary_read(locked_balances_uref, staker) {
+        runtime::revert(StakingError::StakeLocked);
+    }
+
+    decrease_effective_balance(staker, amount);
+}
+
+/// Moves the weighted stake time of `staker` towards now, in proportion to the `amount` just
+/// added to its balance: `(weighted_stake_time * previous_balance + now * amount) / balance`.
+/// It starts the early-exit penalty period, so a small top-up barely delays the exit of a large
+/// stake and deposits from others can't restart the period of a stake
+fn record_stake_time(staker: Address, amount: U256) {
+    let balances_key: Key = runtime::get_key(BALANCES_KEY_NAME).unwrap_or_revert();
+    let balances_uref: URef = balances_key.into_uref().unwrap_or_revert();
+    let weighted_stake_times_key: Key = runtime::get_key(WEIGHTED_STAKE_TIMES_KEY_NAME).unwrap_or_revert();
+    let weighted_stake_times_uref: URef = weighted_stake_times_key.into_uref().unwrap_or_revert();
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+
+    // balance already includes `amount`
+    let balance: U256 = dictionary_read(balances_uref, staker);
+    let previous_balance: U256 = balance - amount;
+    let weighted_stake_time: U256 = dictionary_read(weighted_stake_times_uref, staker);
+
+    let stake_time: U256 = weighted_stake_time
+        .checked_mul(previous_balance)
+        .and_then(|weighted| weighted.checked_add(current_block_time.checked_mul(amount)?))
+        .ok_or(StakingError::Overflow).unwrap_or_revert()
+        / balance;
+
+    dictionary_write(weighted_stake_times_uref, staker, stake_time);
+}
+
+/// Takes the early-exit penalty out of `amount` withdrawn by `staker` and returns the rest.
+/// Stake already left the balances, so a redistributed fee only goes to the stakers left
+fn charge_penalty(staker: Address, amount: U256) -> U256 {
+    let penalty_rate: U256 = get_key(PENALTY_RATE_KEY_NAME).unwrap_or_revert();
+    let penalty_period: U256 = get_key(PENALTY_PERIOD_KEY_NAME).unwrap_or_revert();
+
+    let weighted_stake_times_key: Key = runtime::get_key(WEIGHTED_STAKE_TIMES_KEY_NAME).unwrap_or_revert();
+    let weighted_stake_times_uref: URef = weighted_stake_times_key.into_uref().unwrap_or_revert();
+    let weighted_stake_time: U256 = dictionary_read(weighted_stake_times_uref, staker);
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+
+    if penalty_rate.is_zero() || current_block_time >= weighted_stake_time.saturating_add(penalty_period) {
+        return amount;
+    }
+
+    let fee: U256 = amount
+        .checked_mul(penalty_rate)
+        .ok_or(StakingError::Overflow).unwrap_or_revert()
+        / U256::from(PENALTY_RATE_BASE);
+
+    if fee.is_zero() {
+        return amount;
+    }
+
+    let treasury: Option<Address> = get_key(TREASURY_KEY_NAME).unwrap_or_revert();
+
+    match treasury {
+        Some(treasury) => erc20_transfer(STAKE_TOKEN_HASH_KEY_NAME, treasury, fee),
+        None => redistribute_penalty(fee),
+    }
+
+    emit(StakingEvent::PenaltyCharged { staker, amount: fee });
+
+    amount - fee
+}
+
+/// Shares `fee` of Stake Token between the stakers through the reward token slot of the Stake Token.
+/// The fee is kept in the reward reserve when nothing is staked anymore
+fn redistribute_penalty(fee: U256) {
+    let slot: usize = stake_token_reward_slot().ok_or(StakingError::PenaltyNotPayable).unwrap_or_revert();
+
+    // rewards accrued so far are shared by the supply before the fee
+    update_reward_per_token_stored(slot);
+
+    named_key_add(fee, &reward_key_name(REWARD_RESERVE_KEY_NAME, slot));
+
+    let working_supply: U256 = get_key(WORKING_SUPPLY_KEY_NAME).unwrap_or_revert();
+    if working_supply.is_zero() {
+        return;
+    }
+
+    named_key_add(fee, &reward_key_name(OUTSTANDING_REWARDS_KEY_NAME, slot));
+
+    let reward_per_token_stored_key_name: String = reward_key_name(REWARD_PER_TOKEN_STORED_KEY_NAME, slot);
+    let reward_per_token_dust_key_name: String = reward_key_name(REWARD_PER_TOKEN_DUST_KEY_NAME, slot);
+    let reward_per_token_stored: U256 = get_key(&reward_per_token_stored_key_name).unwrap_or_revert();
+    let reward_per_token_dust: U256 = get_key(&reward_per_token_dust_key_name).unwrap_or_revert();
+
+    // same scaling and remainder carry as the rewards of a period
+    let scaled_fee: U256 = fee
+        .checked_mul(U256::from(REWARD_PRECISION))
+        .and_then(|fee| fee.checked_add(reward_per_token_dust))
+        .ok_or(StakingError::Overflow).unwrap_or_revert();
+
+    set_key(
+        &reward_per_token_stored_key_name,
+        reward_per_token_stored
+            .checked_add(scaled_fee / working_supply)
+            .ok_or(StakingError::Overflow).unwrap_or_revert()
+    );
+    set_key(&reward_per_token_dust_key_name, scaled_fee % working_supply);
+}
+
+/// Reward token slot of the Stake Token, if it is one of the reward tokens
+fn stake_token_reward_slot() -> Option<usize> {
+    let stake_token: Key = get_key(STAKE_TOKEN_HASH_KEY_NAME).unwrap_or_revert();
+    let reward_tokens: Vec<Key> = get_key(REWARD_TOKENS_KEY_NAME).unwrap_or_revert();
+
+    reward_tokens.iter().position(|token| *token == stake_token)
+}
+
+/// Reverts when the owner replaces a token so that an early-exit penalty without a treasury
+/// could not be redistributed anymore
+fn when_penalty_payable() {
+    let penalty_rate: U256 = get_key(PENALTY_RATE_KEY_NAME).unwrap_or_revert();
+    let treasury: Option<Address> = get_key(TREASURY_KEY_NAME).unwrap_or_revert();
+
+    if !penalty_rate.is_zero() && treasury.is_none() && stake_token_reward_slot().is_none() {
+        runtime::revert(StakingError::PenaltyNotPayable);
+    }
+}
+
+/// Records the current balance of `staker` and the total supply in their checkpoint histories,
+/// called after every change of "balances" and "total_supply"
+fn checkpoint_balance(staker: Address) {
+    let balances_key: Key = runtime::get_key(BALANCES_KEY_NAME).unwrap_or_revert();
+    let balances_uref: URef = balances_key.into_uref().unwrap_or_revert();
+    let balance_checkpoints_key: Key = runtime::get_key(BALANCE_CHECKPOINTS_KEY_NAME).unwrap_or_revert();
+    let balance_checkpoints_uref: URef = balance_checkpoints_key.into_uref().unwrap_or_revert();
+    let balance_checkpoint_counts_key: Key = runtime::get_key(BALANCE_CHECKPOINT_COUNTS_KEY_NAME).unwrap_or_revert();
+    let balance_checkpoint_counts_uref: URef = balance_checkpoint_counts_key.into_uref().unwrap_or_revert();
+    let total_supply_checkpoints_key: Key = runtime::get_key(TOTAL_SUPPLY_CHECKPOINTS_KEY_NAME).unwrap_or_revert();
+    let total_supply_checkpoints_uref: URef = total_supply_checkpoints_key.into_uref().unwrap_or_revert();
+
+    let balance_checkpoint_count_item_key: String = make_dictionary_item_key(staker);
+    let balance_checkpoint_count: u32 = dictionary_get(balance_checkpoint_counts_uref, &balance_checkpoint_count_item_key).unwrap_or_default();
+    let balance_checkpoint_count: u32 = push_checkpoint(
+        balance_checkpoints_uref,
+        balance_checkpoint_count,
+        |index| make_indexed_item_key(staker, index),
+        dictionary_read(balances_uref, staker)
+    );
+    storage::dictionary_put(balance_checkpoint_counts_uref, &balance_checkpoint_count_item_key, balance_checkpoint_count);
+
+    let total_supply_checkpoint_count: u32 = get_key(TOTAL_SUPPLY_CHECKPOINT_COUNT_KEY_NAME).unwrap_or_revert();
+    let total_supply_checkpoint_count: u32 = push_checkpoint(
+        total_supply_checkpoints_uref,
+        total_supply_checkpoint_count,
+        |index| index.to_string(),
+        get_key(TOTAL_SUPPLY_KEY_NAME).unwrap_or_revert()
+    );
+    set_key(TOTAL_SUPPLY_CHECKPOINT_COUNT_KEY_NAME, total_supply_checkpoint_count);
+}
+
+/// Appends (current block time, `value`) to the `count` checkpoints in `checkpoints_uref`,
+/// whose item keys are given by `item_key`. A checkpoint of the same block time is replaced,
+/// so block times stay strictly increasing. Returns the new number of checkpoints
+fn push_checkpoint<F: Fn(u32) -> String>(checkpoints_uref: URef, count: u32, item_key: F, value: U256) -> u32 {
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+
+    if count > 0 {
+        let (last_time, _): (U256, U256) = dictionary_get(checkpoints_uref, &item_key(count - 1)).unwrap_or_revert();
+
+        if last_time == current_block_time {
+            storage::dictionary_put(checkpoints_uref, &item_key(count - 1), (current_block_time, value));
+            return count;
+        }
+    }
+
+    storage::dictionary_put(checkpoints_uref, &item_key(count), (current_block_time, value));
+
+    count + 1
+}
+
+/// Value of the last of the `count` checkpoints in `checkpoints_uref` recorded at or before `timestamp`,
+/// found by binary search. Zero when there is none
+fn checkpoint_at<F: Fn(u32) -> String>(checkpoints_uref: URef, count: u32, item_key: F, timestamp: U256) -> U256 {
+    // first checkpoint after `timestamp` is at `low` once the search is over
+    let mut low: u32 = 0;
+    let mut high: u32 = count;
+
+    while low < high {
+        let middle: u32 = low + (high - low) / 2;
+        let (time, _): (U256, U256) = dictionary_get(checkpoints_uref, &item_key(middle)).unwrap_or_revert();
+
+        if time <= timestamp {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+
+    if low == 0 {
+        return U256::zero();
+    }
+
+    let (_, value): (U256, U256) = dictionary_get(checkpoints_uref, &item_key(low - 1)).unwrap_or_revert();
+
+    value
+}
+
+/// Reverts unless `timestamp` is before the current block time, later balances can still change
+fn when_snapshot_final(timestamp: U256) {
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+
+    if timestamp >= current_block_time {
+        runtime::revert(StakingError::SnapshotNotFinal);
+    }
+}
+
+/// Adds `amount` to the unbonding queue of `staker`, claimable after the unbonding period
+fn queue_unbonding(staker: Address, amount: U256) {
+    let unbonding_entries_key: Key = runtime::get_key(UNBONDING_ENTRIES_KEY_NAME).unwrap_or_revert();
+    let unbonding_entries_uref: URef = unbonding_entries_key.into_uref().unwrap_or_revert();
+    let unbonding_entries_item_key: String = make_dictionary_item_key(staker);
+
+    let unbonding_period: U256 = get_key(UNBONDING_PERIOD_KEY_NAME).unwrap_or_revert();
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+    let unlock_time: U256 = current_block_time
+        .checked_add(unbonding_period)
+        .ok_or(StakingError::Overflow).unwrap_or_revert();
+
+    let mut unbonding_entries: Vec<(U256, U256)> = dictionary_get(unbonding_entries_uref, &unbonding_entries_item_key).unwrap_or_default();
+    unbonding_entries.push((amount, unlock_time));
+
+    storage::dictionary_put(unbonding_entries_uref, &unbonding_entries_item_key, unbonding_entries);
+    named_key_add(amount, TOTAL_UNBONDING_KEY_NAME);
+}
+
+#[no_mangle]
+pub extern "C" fn get_reward() {
+    
+    when_not_paused();
+
+    let staker: Address = get_immediate_caller_address().unwrap_or_revert();
+
+    update_reward(staker);
+
+    // every reward token is paid in one call
+    for slot in 0..reward_token_count() {
+        pay_reward(staker, staker, slot);
+    }
+}
+
+/// Pays every reward of the caller to `recipient`
+#[no_mangle]
+pub extern "C" fn get_reward_to() {
+
+    when_not_paused();
+
+    let staker: Address = get_immediate_caller_address().unwrap_or_revert();
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_KEY_NAME);
+
+    when_recipient_paid_directly(staker, recipient);
+
+    update_reward(staker);
+
+    for slot in 0..reward_token_count() {
+        pay_reward(staker, recipient, slot);
+    }
+}
+
+/// Pays only the rewards of `reward_token`, the other ones stay credited
+#[no_mangle]
+pub extern "C" fn get_reward_for() {
+
+    when_not_paused();
+
+    let reward_token: Key = runtime::get_named_arg(REWARD_TOKEN_KEY_NAME);
+    let slot: usize = reward_slot(reward_token);
+
+    let staker: Address = get_immediate_caller_address().unwrap_or_revert();
+
+    update_reward_for(staker, slot);
+
+    pay_reward(staker, staker, slot);
+}
+
+/// Restakes the rewards of the caller, only when the Stake Token is the Reward Token and rewards don't vest.
+/// Tokens are already held by the contract, they move from the reward reserve to the principal
+#[no_mangle]
+pub extern "C" fn compound() {
+
+    when_not_paused();
+
+    let compounding_enabled: bool = get_key(COMPOUNDING_ENABLED_KEY_NAME).unwrap_or_revert();
+    if !compounding_enabled {
+        runtime::revert(StakingError::CompoundingDisabled);
+    }
+
+    let staker: Address = get_immediate_caller_address().unwrap_or_revert();
+    when_allowlisted(staker);
+
+    let balances_key: Key = runtime::get_key(BALANCES_KEY_NAME).unwrap_or_revert();
+    let balances_uref: URef = balances_key.into_uref().unwrap_or_revert();
+    let rewards_uref: URef = reward_dictionary_uref(REWARDS_KEY_NAME, 0);
+
+    update_reward(staker);
+
+    let staker_reward: U256 = dictionary_read(rewards_uref, staker);
+
+    if staker_reward.is_zero() {
+        runtime::revert(StakingError::ZeroAmount);
+    }
+
+    // rewards can't be restaked out of the principal of other stakers,
+    // what the reserve can't cover stays owed
+    let amount: U256 = take_from_reward_reserve(0, staker_reward);
+
+    if amount.is_zero() {
+        runtime::revert(StakingError::RewardPoolEmpty);
+    }
+
+    dictionary_write(rewards_uref, staker, staker_reward - amount);
+
+    // restaked rewards count against the caps, not the minimum deposit
+    when_within_stake_caps(staker, amount);
+
+    // update total_supply and balance of caller like 'stake' does
+    named_key_add(amount, TOTAL_SUPPLY_KEY_NAME);
+    dictionary_add(balances_uref, staker, amount).unwrap_or_revert();
+    checkpoint_balance(staker);
+    increase_effective_balance(staker, amount);
+
+    emit(StakingEvent::Compounded { staker, amount });
+}
+
+/// Transfers the rewards of the reward token in `slot` credited to `staker`.
+/// When the reserve can't cover all of them it is paid out and the rest stays owed.
+/// With a vesting period they go to the escrow of `staker` instead, `recipient` is only paid directly
+fn pay_reward(staker: Address, recipient: Address, slot: usize) {
+
+    let rewards_uref: URef = reward_dictionary_uref(REWARDS_KEY_NAME, slot);
+    let reward_token_hash_key_name: String = reward_key_name(REWARD_TOKEN_HASH_KEY_NAME, slot);
+
+    // get reward_value of the caller stored in "rewards" dictionary
+    let staker_reward: U256 = dictionary_read(rewards_uref, staker);
+
+    if staker_reward.is_zero() {
+        return;
+    }
+
+    let amount: U256 = take_from_reward_reserve(slot, staker_reward);
+
+    if amount.is_zero() {
+        return;
+    }
+    
+    // keep the unpaid part of the rewards in the dictionary
+    dictionary_write(rewards_uref, staker, staker_reward - amount);
+
+    let vesting_period: U256 = get_key(VESTING_PERIOD_KEY_NAME).unwrap_or_revert();
+
+    if vesting_period.is_zero() {
+        // Transfer `amount` of Reward Token to recipient
+        erc20_transfer(
+            &reward_token_hash_key_name,
+            recipient,
+            amount
+        );
+    } else {
+        // `staker` claims from its own escrow, nobody else can add tranches to it
+        escrow_reward(staker, slot, amount, vesting_period);
+    }
+
+    let reward_token: Key = get_key(&reward_token_hash_key_name).unwrap_or_revert();
+    emit(StakingEvent::RewardPaid { staker, amount, reward_token });
+}
+
+/// Reverts if rewards of `staker` would vest while the caller asked for them to go to `recipient`,
+/// escrows only belong to the staker
+fn when_recipient_paid_directly(staker: Address, recipient: Address) {
+    let vesting_period: U256 = get_key(VESTING_PERIOD_KEY_NAME).unwrap_or_revert();
+
+    if !vesting_period.is_zero() && recipient != staker {
+        runtime::revert(StakingError::RecipientWhileVesting);
+    }
+}
+
+/// Locks `amount` in the escrow of `staker` for `slot` as a new tranche vesting from now
+/// to the end of the vesting period, earlier tranches keep their own schedule
+fn escrow_reward(staker: Address, slot: usize, amount: U256, vesting_period: U256) {
+    let vesting_escrows_uref: URef = reward_dictionary_uref(VESTING_ESCROWS_KEY_NAME, slot);
+    let vesting_escrows_item_key: String = make_dictionary_item_key(staker);
+
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+    let vesting_end: U256 = current_block_time
+        .checked_add(vesting_period)
+        .ok_or(StakingError::Overflow).unwrap_or_revert();
+
+    let mut vesting_escrow: Vec<(U256, U256, U256)> = dictionary_get(vesting_escrows_uref, &vesting_escrows_item_key).unwrap_or_default();
+
+    // rewards escrowed in the same block share one tranche
+    match vesting_escrow.last_mut() {
+        Some((locked, vesting_start, _)) if *vesting_start == current_block_time => {
+            *locked = locked
+                .checked_add(amount)
+                .ok_or(StakingError::Overflow).unwrap_or_revert();
+        }
+        _ => vesting_escrow.push((amount, current_block_time, vesting_end)),
+    }
+
+    storage::dictionary_put(vesting_escrows_uref, &vesting_escrows_item_key, vesting_escrow);
+    named_key_add(amount, &reward_key_name(ESCROWED_REWARDS_KEY_NAME, slot));
+}
+
+/// Moves the part of every escrow tranche of `address` for `slot` vested so far to its unlocked rewards,
+/// the rest of each tranche keeps vesting until its own end. Returns the unlocked rewards
+fn release_vested(address: Address, slot: usize) -> U256 {
+    let vesting_escrows_uref: URef = reward_dictionary_uref(VESTING_ESCROWS_KEY_NAME, slot);
+    let vesting_escrows_item_key: String = make_dictionary_item_key(address);
+    let vested_rewards_uref: URef = reward_dictionary_uref(VESTED_REWARDS_KEY_NAME, slot);
+
+    let vesting_escrow: Vec<(U256, U256, U256)> = dictionary_get(vesting_escrows_uref, &vesting_escrows_item_key).unwrap_or_default();
+
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+    let vested: U256 = escrow_vested_amount(&vesting_escrow, current_block_time);
+
+    if vested.is_zero() {
+        return dictionary_read(vested_rewards_uref, address);
+    }
+
+    // fully vested tranches are dropped
+    let vesting_escrow: Vec<(U256, U256, U256)> = vesting_escrow
+        .into_iter()
+        .map(|tranche| {
+            let (locked, _, vesting_end) = tranche;
+            (locked - vested_amount(tranche, current_block_time), current_block_time, vesting_end)
+        })
+        .filter(|(locked, _, _)| !locked.is_zero())
+        .collect();
+    storage::dictionary_put(vesting_escrows_uref, &vesting_escrows_item_key, vesting_escrow);
+
+    dictionary_add(vested_rewards_uref, address, vested).unwrap_or_revert();
+
+    dictionary_read(vested_rewards_uref, address)
+}
+
+/// Sum over the tranches of an escrow of the parts vested at `time`
+fn escrow_vested_amount(vesting_escrow: &[(U256, U256, U256)], time: U256) -> U256 {
+    vesting_escrow
+        .iter()
+        .fold(U256::zero(), |vested, tranche| {
+            vested
+                .checked_add(vested_amount(*tranche, time))
+                .ok_or(StakingError::Overflow).unwrap_or_revert()
+        })
+}
+
+/// Part of the `(locked, start, end)` escrow tranche vested at `time`, linear from start to end
+fn vested_amount((locked, vesting_start, vesting_end): (U256, U256, U256), time: U256) -> U256 {
+    if locked.is_zero() || time <= vesting_start {
+        return U256::zero();
+    }
+
+    if time >= vesting_end {
+        return locked;
+    }
+
+    locked
+        .checked_mul(time - vesting_start)
+        .ok_or(StakingError::Overflow).unwrap_or_revert()
+        / (vesting_end - vesting_start)
+}
+
+/// Transfers to the caller every escrowed reward vested so far, for all the reward tokens
+#[no_mangle]
+pub extern "C" fn claim_vested() {
+
+    when_not_paused();
+
+    let staker: Address = get_immediate_caller_address().unwrap_or_revert();
+    let mut total_claimed: U256 = U256::zero();
+
+    for slot in 0..reward_token_count() {
+        let amount: U256 = release_vested(staker, slot);
+
+        if amount.is_zero() {
+            continue;
+        }
+
+        dictionary_write(reward_dictionary_uref(VESTED_REWARDS_KEY_NAME, slot), staker, U256::zero());
+        named_key_sub(amount, &reward_key_name(ESCROWED_REWARDS_KEY_NAME, slot));
+
+        let reward_token_hash_key_name: String = reward_key_name(REWARD_TOKEN_HASH_KEY_NAME, slot);
+        erc20_transfer(
+            &reward_token_hash_key_name,
+            staker,
+            amount
+        );
+
+        let reward_token: Key = get_key(&reward_token_hash_key_name).unwrap_or_revert();
+        emit(StakingEvent::VestedClaimed { staker, amount, reward_token });
+
+        total_claimed += amount;
+    }
+
+    if total_claimed.is_zero() {
+        runtime::revert(StakingError::NothingVested);
+    }
+}
+
+/// Returns the raw principal of the caller right away without touching the Reward Token,
+/// all the rewards accrued so far are forfeited.
+/// Lock positions are released as well, losing the rewards is the price of the early unlock.
+/// As the exit of last resort it skips the reward checkpoint, the early-exit penalty
+/// and the unbonding period, forfeited rewards stay counted as outstanding in the reserve
+#[no_mangle]
+pub extern "C" fn emergency_withdraw() {
+
+    let staker: Address = get_immediate_caller_address().unwrap_or_revert();
+    let balances_key: Key = runtime::get_key(BALANCES_KEY_NAME).unwrap_or_revert();
+    let balances_uref: URef = balances_key.into_uref().unwrap_or_revert();
+
+    let amount: U256 = dictionary_read(balances_uref, staker);
+
+    if amount.is_zero() {
+        runtime::revert(StakingError::ZeroAmount);
+    }
+
+    // update total_supply
+    named_key_sub(amount, TOTAL_SUPPLY_KEY_NAME);
+
+    // clear the position of the caller, rewards of every reward token are forfeited
+    dictionary_write(balances_uref, staker, U256::from(0));
+    checkpoint_balance(staker);
+
+    for slot in 0..reward_token_count() {
+        dictionary_write(reward_dictionary_uref(REWARDS_KEY_NAME, slot), staker, U256::from(0));
+        dictionary_write(reward_dictionary_uref(USER_REWARD_PER_TOKEN_PAID_KEY_NAME, slot), staker, U256::from(0));
+    }
+
+    let effective_balances_key: Key = runtime::get_key(EFFECTIVE_BALANCES_KEY_NAME).unwrap_or_revert();
+    let effective_balances_uref: URef = effective_balances_key.into_uref().unwrap_or_revert();
+    decrease_effective_balance(staker, dictionary_read(effective_balances_uref, staker));
+
+    release_lock_positions(staker);
+
+    // Transfer the whole stake of Stake Token from the stake contract to caller
+    erc20_transfer(
+        STAKE_TOKEN_HASH_KEY_NAME,
+        staker,
+        amount
+    );
+
+    emit(StakingEvent::Withdrawn { staker, amount });
+}
+
+/// Stakes `amount` locked for `lock_duration`, until then it earns rewards
+/// with the multiplier of the lock tier of that duration
+#[no_mangle]
+pub extern "C" fn stake_locked() {
+
+    when_not_paused();
+
+    let amount: U256 = runtime::get_named_arg(AMOUNT_KEY_NAME);
+    let lock_duration: U256 = runtime::get_named_arg(LOCK_DURATION_KEY_NAME);
+
+    if amount.is_zero() {
+        runtime::revert(StakingError::ZeroAmount);
+    }
+
+    let multiplier: U256 = lock_multiplier(lock_duration);
+
+    let staker: Address = get_immediate_caller_address().unwrap_or_revert();
+    when_allowlisted(staker);
+    when_within_stake_limits(staker, amount);
+
+    let balances_key: Key = runtime::get_key(BALANCES_KEY_NAME).unwrap_or_revert();
+    let locked_balances_key: Key = runtime::get_key(LOCKED_BALANCES_KEY_NAME).unwrap_or_revert();
+    let lock_positions_key: Key = runtime::get_key(LOCK_POSITIONS_KEY_NAME).unwrap_or_revert();
+    let lock_position_counts_key: Key = runtime::get_key(LOCK_POSITION_COUNTS_KEY_NAME).unwrap_or_revert();
+    let balances_uref: URef = balances_key.into_uref().unwrap_or_revert();
+    let locked_balances_uref: URef = locked_balances_key.into_uref().unwrap_or_revert();
+    let lock_positions_uref: URef = lock_positions_key.into_uref().unwrap_or_revert();
+    let lock_position_counts_uref: URef = lock_position_counts_key.into_uref().unwrap_or_revert();
+
+    update_reward(staker);
+
+    // update total_supply
+    named_key_add(amount, TOTAL_SUPPLY_KEY_NAME);
+
+    // update balance of caller, all of it is locked
+    dictionary_add(balances_uref, staker, amount).unwrap_or_revert();
+    dictionary_add(locked_balances_uref, staker, amount).unwrap_or_revert();
+    checkpoint_balance(staker);
+
+    let effective_amount: U256 = amount
+        .checked_mul(multiplier)
+        .ok_or(StakingError::Overflow).unwrap_or_revert()
+        / U256::from(MULTIPLIER_BASE);
+
+    increase_effective_balance(staker, effective_amount);
+
+    // open a new lock position
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+    let unlock_time: U256 = current_block_time
+        .checked_add(lock_duration)
+        .ok_or(StakingError::Overflow).unwrap_or_revert();
+
+    let position_count_item_key: String = make_dictionary_item_key(staker);
+    let position_id: u32 = dictionary_get(lock_position_counts_uref, &position_count_item_key).unwrap_or_default();
+
+    storage::dictionary_put(
+        lock_positions_uref,
+        &make_indexed_item_key(staker, position_id),
+        (amount, unlock_time, effective_amount)
+    );
+    storage::dictionary_put(lock_position_counts_uref, &position_count_item_key, position_id + 1);
+
+    record_stake_time(staker, amount);
+
+    // Transfer `amount` of Stake Token from caller to the stake contract
+    erc20_transfer_from(
+        STAKE_TOKEN_HASH_KEY_NAME,
+        staker,
+        amount
+    );
+
+    emit(StakingEvent::Staked { staker, amount });
+}
+
+/// Unlocks an expired lock position, its stake can be withdrawn and earns rewards 1x from now on.
+/// Anyone can unlock an expired position, so the multiplier doesn't outlive the lock
+#[no_mangle]
+pub extern "C" fn unlock_position() {
+
+    let staker: Address = runtime::get_named_arg(ADDRESS_KEY_NAME);
+    let position_id: u32 = runtime::get_named_arg(POSITION_ID_KEY_NAME);
+
+    let locked_balances_key: Key = runtime::get_key(LOCKED_BALANCES_KEY_NAME).unwrap_or_revert();
+    let lock_positions_key: Key = runtime::get_key(LOCK_POSITIONS_KEY_NAME).unwrap_or_revert();
+    let locked_balances_uref: URef = locked_balances_key.into_uref().unwrap_or_revert();
+    let lock_positions_uref: URef = lock_positions_key.into_uref().unwrap_or_revert();
+
+    let position_item_key: String = make_indexed_item_key(staker, position_id);
+    let (amount, unlock_time, effective_amount): (U256, U256, U256) =
+        dictionary_get(lock_positions_uref, &position_item_key).unwrap_or_default();
+
+    if amount.is_zero() {
+        runtime::revert(StakingError::UnknownPosition);
+    }
+
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+    if current_block_time < unlock_time {
+        runtime::revert(StakingError::StakeLocked);
+    }
+
+    update_reward(staker);
+
+    // only the boost is removed, the stake itself keeps earning 1x
+    decrease_effective_balance(staker, effective_amount - amount);
+    dictionary_sub(locked_balances_uref, staker, amount).unwrap_or_revert();
+
+    storage::dictionary_put(lock_positions_uref, &position_item_key, (U256::zero(), U256::zero(), U256::zero()));
+}
+
+#[no_mangle]
+pub extern "C" fn set_lock_tiers() {
+
+    only_owner();
+
+    let lock_tiers: Vec<(U256, U256)> = runtime::get_named_arg(LOCK_TIERS_KEY_NAME);
+
+    for (lock_duration, multiplier) in lock_tiers.iter() {
+        if lock_duration.is_zero() || *multiplier < U256::from(MULTIPLIER_BASE) {
+            runtime::revert(StakingError::InvalidLockTier);
+        }
+    }
+
+    // existing positions keep the multiplier they were opened with
+    set_key(LOCK_TIERS_KEY_NAME, lock_tiers);
+}
+
+/// Multiplier of the lock tier with `lock_duration`
+fn lock_multiplier(lock_duration: U256) -> U256 {
+    let lock_tiers: Vec<(U256, U256)> = get_key(LOCK_TIERS_KEY_NAME).unwrap_or_revert();
+
+    lock_tiers
+        .into_iter()
+        .find(|(tier_duration, _)| *tier_duration == lock_duration)
+        .map(|(_, multiplier)| multiplier)
+        .ok_or(StakingError::InvalidLockDuration).unwrap_or_revert()
+}
+
+/// Clears every lock position of `staker` and its locked balance
+fn release_lock_positions(staker: Address) {
+    let locked_balances_key: Key = runtime::get_key(LOCKED_BALANCES_KEY_NAME).unwrap_or_revert();
+    let lock_positions_key: Key = runtime::get_key(LOCK_POSITIONS_KEY_NAME).unwrap_or_revert();
+    let lock_position_counts_key: Key = runtime::get_key(LOCK_POSITION_COUNTS_KEY_NAME).unwrap_or_revert();
+    let locked_balances_uref: URef = locked_balances_key.into_uref().unwrap_or_revert();
+    let lock_positions_uref: URef = lock_positions_key.into_uref().unwrap_or_revert();
+    let lock_position_counts_uref: URef = lock_position_counts_key.into_uref().unwrap_or_revert();
+
+    let position_count: u32 = dictionary_get(lock_position_counts_uref, &make_dictionary_item_key(staker)).unwrap_or_default();
+
+    for position_id in 0..position_count {
+        storage::dictionary_put(
+            lock_positions_uref,
+            &make_indexed_item_key(staker, position_id),
+            (U256::zero(), U256::zero(), U256::zero())
+        );
+    }
+
+    dictionary_write(locked_balances_uref, staker, U256::from(0));
+}
+
+/// Adds `amount` to the effective balance of `staker` and to the effective supply,
+/// its working balance follows
+fn increase_effective_balance(staker: Address, amount: U256) {
+    let effective_balances_key: Key = runtime::get_key(EFFECTIVE_BALANCES_KEY_NAME).unwrap_or_revert();
+    let effective_balances_uref: URef = effective_balances_key.into_uref().unwrap_or_revert();
+
+    named_key_add(amount, EFFECTIVE_SUPPLY_KEY_NAME);
+    dictionary_add(effective_balances_uref, staker, amount).unwrap_or_revert();
+
+    update_working_balance(staker);
+}
+
+/// Removes `amount` from the effective balance of `staker` and from the effective supply,
+/// its working balance follows
+fn decrease_effective_balance(staker: Address, amount: U256) {
+    let effective_balances_key: Key = runtime::get_key(EFFECTIVE_BALANCES_KEY_NAME).unwrap_or_revert();
+    let effective_balances_uref: URef = effective_balances_key.into_uref().unwrap_or_revert();
+
+    named_key_sub(amount, EFFECTIVE_SUPPLY_KEY_NAME);
+    dictionary_sub(effective_balances_uref, staker, amount).unwrap_or_revert();
+
+    update_working_balance(staker);
+}
+
+/// Recomputes the working balance of `staker` and the working supply.
+/// Rewards of `staker` have to be checkpointed first, they accrue on the working balance
+fn update_working_balance(staker: Address) {
+    let effective_balances_key: Key = runtime::get_key(EFFECTIVE_BALANCES_KEY_NAME).unwrap_or_revert();
+    let effective_balances_uref: URef = effective_balances_key.into_uref().unwrap_or_revert();
+    let working_balances_key: Key = runtime::get_key(WORKING_BALANCES_KEY_NAME).unwrap_or_revert();
+    let working_balances_uref: URef = working_balances_key.into_uref().unwrap_or_revert();
+
+    let effective_balance: U256 = dictionary_read(effective_balances_uref, staker);
+
+    let working_balance: U256 = if runtime::has_key(BOOST_TOKEN_HASH_KEY_NAME) {
+        boosted_balance(staker, effective_balance)
+    } else {
+        effective_balance
+    };
+
+    named_key_sub(dictionary_read(working_balances_uref, staker), WORKING_SUPPLY_KEY_NAME);
+    named_key_add(working_balance, WORKING_SUPPLY_KEY_NAME);
+    dictionary_write(working_balances_uref, staker, working_balance);
+}
+
+/// Working balance of `staker` for `effective_balance`: UNBOOSTED_SHARE of it, plus a share of
+/// the effective supply proportional to the Boost Tokens of `staker`, up to the whole effective balance
+fn boosted_balance(staker: Address, effective_balance: U256) -> U256 {
+    let boost_balances_key: Key = runtime::get_key(BOOST_BALANCES_KEY_NAME).unwrap_or_revert();
+    let boost_balances_uref: URef = boost_balances_key.into_uref().unwrap_or_revert();
+
+    let unboosted_balance: U256 = effective_balance
+        .checked_mul(U256::from(UNBOOSTED_SHARE))
+        .ok_or(StakingError::Overflow).unwrap_or_revert()
+        / U256::from(BOOST_SHARE_BASE);
+
+    let boost_supply: U256 = get_key(BOOST_SUPPLY_KEY_NAME).unwrap_or_revert();
+    if boost_supply.is_zero() {
+        return unboosted_balance;
+    }
+
+    let effective_supply: U256 = get_key(EFFECTIVE_SUPPLY_KEY_NAME).unwrap_or_revert();
+    let boost_balance: U256 = dictionary_read(boost_balances_uref, staker);
+
+    let boost: U256 = effective_supply
+        .checked_mul(boost_balance)
+        .and_then(|boost| boost.checked_mul(U256::from(BOOST_SHARE_BASE - UNBOOSTED_SHARE)))
+        .ok_or(StakingError::Overflow).unwrap_or_revert()
+        / boost_supply
+        / U256::from(BOOST_SHARE_BASE);
+
+    unboosted_balance
+        .checked_add(boost)
+        .ok_or(StakingError::Overflow).unwrap_or_revert()
+        .min(effective_balance)
+}
+
+/// Locks `amount` of Boost Token of the caller, its stake earns up to 2.5x the unboosted rewards
+#[no_mangle]
+pub extern "C" fn lock_boost() {
+
+    when_not_paused();
+
+    if !runtime::has_key(BOOST_TOKEN_HASH_KEY_NAME) {
+        runtime::revert(StakingError::BoostDisabled);
+    }
+
+    let staker: Address = get_immediate_caller_address().unwrap_or_revert();
+    let amount: U256 = runtime::get_named_arg(AMOUNT_KEY_NAME);
+
+    if amount.is_zero() {
+        runtime::revert(StakingError::ZeroAmount);
+    }
+
+    let boost_balances_key: Key = runtime::get_key(BOOST_BALANCES_KEY_NAME).unwrap_or_revert();
+    let boost_balances_uref: URef = boost_balances_key.into_uref().unwrap_or_revert();
+
+    // rewards accrued so far keep the previous boost
+    update_reward(staker);
+
+    named_key_add(amount, BOOST_SUPPLY_KEY_NAME);
+    dictionary_add(boost_balances_uref, staker, amount).unwrap_or_revert();
+
+    update_working_balance(staker);
+
+    erc20_transfer_from(
+        BOOST_TOKEN_HASH_KEY_NAME,
+        staker,
+        amount
+    );
+
+    emit(StakingEvent::BoostLocked { staker, amount });
+}
+
+/// Returns `amount` of the locked Boost Token to the caller, its boost shrinks right away
+#[no_mangle]
+pub extern "C" fn unlock_boost() {
+
+    let staker: Address = get_immediate_caller_address().unwrap_or_revert();
+    let amount: U256 = runtime::get_named_arg(AMOUNT_KEY_NAME);
+
+    if amount.is_zero() {
+        runtime::revert(StakingError::ZeroAmount);
+    }
+
+    let boost_balances_key: Key = runtime::get_key(BOOST_BALANCES_KEY_NAME).unwrap_or_revert();
+    let boost_balances_uref: URef = boost_balances_key.into_uref().unwrap_or_revert();
+
+    update_reward(staker);
+
+    dictionary_sub(boost_balances_uref, staker, amount).unwrap_or_revert();
+    named_key_sub(amount, BOOST_SUPPLY_KEY_NAME);
+
+    update_working_balance(staker);
+
+    erc20_transfer(
+        BOOST_TOKEN_HASH_KEY_NAME,
+        staker,
+        amount
+    );
+
+    emit(StakingEvent::BoostUnlocked { staker, amount });
+}
+
+/// Sets the token locked with 'lock_boost', working balances of the stakers
+/// switch to the boost formula at their next checkpoint
+#[no_mangle]
+pub extern "C" fn set_boost_token() {
+
+    only_owner();
+
+    let boost_token: Key = runtime::get_named_arg(BOOST_TOKEN_HASH_KEY_NAME);
+
+    // Locked tokens could not be returned from another token contract
+    let boost_supply: U256 = get_key(BOOST_SUPPLY_KEY_NAME).unwrap_or_revert();
+    if !boost_supply.is_zero() {
+        runtime::revert(StakingError::BoostLocked);
+    }
+
+    // Wrong token keys fail here instead of at the first lock
+    erc20_decimals(boost_token);
+
+    set_key(BOOST_TOKEN_HASH_KEY_NAME, boost_token);
+}
+
+#[no_mangle]
+pub extern "C" fn notify_reward_amount() {
+
+    only_owner();
+
+    let reward_token: Key = runtime::get_named_arg(REWARD_TOKEN_KEY_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_KEY_NAME);
+    let duration: U256 = runtime::get_named_arg(DURATION_KEY_NAME);
+
+    if duration.is_zero() {
+        runtime::revert(StakingError::ZeroDuration);
+    }
+
+    let slot: usize = reward_slot(reward_token);
+    let reward_token_hash_key_name: String = reward_key_name(REWARD_TOKEN_HASH_KEY_NAME, slot);
+    let reward_rate_key_name: String = reward_key_name(REWARD_RATE_KEY_NAME, slot);
+    let period_finish_key_name: String = reward_key_name(PERIOD_FINISH_KEY_NAME, slot);
+
+    let funder: Address = get_immediate_caller_address().unwrap_or_revert();
+
+    // accrue everything owed under the old rate before it changes
+    update_reward_per_token_stored(slot);
+
+    // Transfer `amount` of Reward Token from caller to the stake contract
+    erc20_transfer_from(
+        &reward_token_hash_key_name,
+        funder,
+        amount
+    );
+    named_key_add(amount, &reward_key_name(REWARD_RESERVE_KEY_NAME, slot));
+
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+
+    // rewards not yet distributed in the running period are rolled into the new one
+    let leftover: U256 = undistributed_rewards(slot);
+    let reward_rate: U256 = amount
+        .checked_add(leftover)
+        .ok_or(StakingError::Overflow).unwrap_or_revert()
+        / duration;
+
+    // The reserve should cover the whole period on top of the rewards already owed,
+    // otherwise the rate would promise more than can ever be claimed
+    promise_rewards(
+        slot,
+        leftover,
+        reward_rate
+            .checked_mul(duration)
+            .ok_or(StakingError::Overflow).unwrap_or_revert()
+    );
+
+    // a constant rate replaces the emission schedule
+    set_key(&reward_rate_key_name, reward_rate);
+    set_key(&reward_key_name(EMISSION_SCHEDULE_KEY_NAME, slot), Vec::<(U256, U256)>::new());
+    set_key(&reward_key_name(LAST_UPDATE_KEY_NAME, slot), current_block_time);
+    set_key(
+        &period_finish_key_name,
+        current_block_time
+            .checked_add(duration)
+            .ok_or(StakingError::Overflow).unwrap_or_revert()
+    );
+
+    emit(StakingEvent::RewardAdded { funder, amount, reward_token });
+    emit(StakingEvent::RewardRateUpdated { caller: funder, reward_rate, reward_token });
+}
+
+/// Adds `amount` of `reward_token` to the reward reserve without starting a reward period.
+/// Anyone can fund the reserve, rewards owed beyond it are paid once it is topped up
+#[no_mangle]
+pub extern "C" fn fund_rewards() {
+
+    let reward_token: Key = runtime::get_named_arg(REWARD_TOKEN_KEY_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_KEY_NAME);
+
+    if amount.is_zero() {
+        runtime::revert(StakingError::ZeroAmount);
+    }
+
+    let slot: usize = reward_slot(reward_token);
+    let funder: Address = get_immediate_caller_address().unwrap_or_revert();
+
+    erc20_transfer_from(
+        &reward_key_name(REWARD_TOKEN_HASH_KEY_NAME, slot),
+        funder,
+        amount
+    );
+    named_key_add(amount, &reward_key_name(REWARD_RESERVE_KEY_NAME, slot));
+
+    emit(StakingEvent::RewardAdded { funder, amount, reward_token });
+}
+
+/// Transfers `amount` of a token the pool doesn't account for to the owner, like a previous
+/// Reward Token left over after 'set_reward_token' or tokens sent to the contract by mistake
+#[no_mangle]
+pub extern "C" fn recover_tokens() {
+
+    only_owner();
+
+    let token: Key = runtime::get_named_arg(TOKEN_KEY_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_KEY_NAME);
+
+    // Stakes, locked boost tokens and reward reserves belong to the stakers
+    let stake_token: Key = get_key(STAKE_TOKEN_HASH_KEY_NAME).unwrap_or_revert();
+    let boost_token: Option<Key> = get_key(BOOST_TOKEN_HASH_KEY_NAME);
+    let reward_tokens: Vec<Key> = get_key(REWARD_TOKENS_KEY_NAME).unwrap_or_revert();
+
+    if token == stake_token || boost_token == Some(token) || reward_tokens.contains(&token) {
+        runtime::revert(StakingError::TokenNotRecoverable);
+    }
+
+    let erc20_contract_hash: ContractHash = token
+        .into_hash()
+        .map(ContractHash::new)
+        .ok_or(StakingError::InvalidTokenContract)
+        .unwrap_or_revert();
+
+    let owner: Address = get_immediate_caller_address().unwrap_or_revert();
+
+    let _: () = runtime::call_contract(erc20_contract_hash, TRANSFER_ENTRY_POINT_NAME, runtime_args!{
+        RECIPIENT_RUNTIME_ARG_NAME => owner,
+        AMOUNT_RUNTIME_ARG_NAME => amount
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn set_reward_rate() {
+
+    only_owner();
+
+    let reward_token: Key = runtime::get_named_arg(REWARD_TOKEN_KEY_NAME);
+    let reward_rate: U256 = runtime::get_named_arg(REWARD_RATE_KEY_NAME);
+
+    let slot: usize = reward_slot(reward_token);
+
+    // keep everything accrued under the old rate
+    update_reward_per_token_stored(slot);
+
+    // The rest of the running period has to be covered by the reward reserve
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+    let period_finish: U256 = get_key(&reward_key_name(PERIOD_FINISH_KEY_NAME, slot)).unwrap_or_revert();
+
+    if period_finish > current_block_time {
+        promise_rewards(
+            slot,
+            undistributed_rewards(slot),
+            reward_rate
+                .checked_mul(period_finish - current_block_time)
+                .ok_or(StakingError::Overflow).unwrap_or_revert()
+        );
+    }
+
+    set_key(&reward_key_name(REWARD_RATE_KEY_NAME, slot), reward_rate);
+    set_key(&reward_key_name(EMISSION_SCHEDULE_KEY_NAME, slot), Vec::<(U256, U256)>::new());
+
+    let caller: Address = get_immediate_caller_address().unwrap_or_revert();
+    emit(StakingEvent::RewardRateUpdated { caller, reward_rate, reward_token });
+}
+
+/// Replaces the rate of `reward_token` by `emission_schedule`, (start time, reward rate) segments
+/// sorted by start time. Each rate applies until the start of the next segment, the last one
+/// has to be 0 and ends the period. Nothing is emitted before the first segment.
+/// The reward reserve has to cover the whole schedule on top of the rewards already owed
+#[no_mangle]
+pub extern "C" fn set_emission_schedule() {
+
+    only_owner();
+
+    let reward_token: Key = runtime::get_named_arg(REWARD_TOKEN_KEY_NAME);
+    let emission_schedule: Vec<(U256, U256)> = runtime::get_named_arg(EMISSION_SCHEDULE_KEY_NAME);
+
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+
+    let starts_in_past: bool = emission_schedule
+        .first()
+        .map_or(true, |(segment_start, _)| *segment_start < current_block_time);
+    let ends_with_zero_rate: bool = emission_schedule
+        .last()
+        .map_or(false, |(_, reward_rate)| reward_rate.is_zero());
+    let sorted: bool = emission_schedule
+        .windows(2)
+        .all(|segments| segments[0].0 < segments[1].0);
+
+    if starts_in_past || !ends_with_zero_rate || !sorted || emission_schedule.len() > MAX_EMISSION_SEGMENTS {
+        runtime::revert(StakingError::InvalidEmissionSchedule);
+    }
+
+    let slot: usize = reward_slot(reward_token);
+
+    // keep everything accrued under the old rate
+    update_reward_per_token_stored(slot);
+
+    let undistributed: U256 = undistributed_rewards(slot);
+    let (period_finish, _) = *emission_schedule.last().unwrap_or_revert();
+    let segment_count: usize = emission_schedule.len();
+
+    set_key(&reward_key_name(EMISSION_SCHEDULE_KEY_NAME, slot), emission_schedule);
+    set_key(&reward_key_name(LAST_UPDATE_KEY_NAME, slot), current_block_time);
+    set_key(&reward_key_name(PERIOD_FINISH_KEY_NAME, slot), period_finish);
+
+    promise_rewards(slot, undistributed, emitted_rewards(slot, current_block_time, period_finish));
+
+    let caller: Address = get_immediate_caller_address().unwrap_or_revert();
+    let reward_rate: U256 = reward_rate_at(slot, current_block_time);
+
+    emit(StakingEvent::EmissionScheduleUpdated { caller, reward_rate, reward_token, segment_count });
+}
+
+/// Rate of `slot` at `time`, from its emission schedule when one is set
+fn reward_rate_at(slot: usize, time: U256) -> U256 {
+    let emission_schedule: Vec<(U256, U256)> = get_key(&reward_key_name(EMISSION_SCHEDULE_KEY_NAME, slot)).unwrap_or_revert();
+
+    if emission_schedule.is_empty() {
+        return get_key(&reward_key_name(REWARD_RATE_KEY_NAME, slot)).unwrap_or_revert();
+    }
+
+    emission_schedule
+        .iter()
+        .rev()
+        .find(|(segment_start, _)| *segment_start <= time)
+        .map_or(U256::from(0), |(_, reward_rate)| *reward_rate)
+}
+
+/// Rewards `slot` emits between `from` and `to`, integrated over the segments
+/// of its emission schedule when one is set
+fn emitted_rewards(slot: usize, from: U256, to: U256) -> U256 {
+    let emission_schedule: Vec<(U256, U256)> = get_key(&reward_key_name(EMISSION_SCHEDULE_KEY_NAME, slot)).unwrap_or_revert();
+
+    if emission_schedule.is_empty() {
+        let reward_rate: U256 = get_key(&reward_key_name(REWARD_RATE_KEY_NAME, slot)).unwrap_or_revert();
+
+        return reward_rate
+            .checked_mul(to.saturating_sub(from))
+            .ok_or(StakingError::Overflow).unwrap_or_revert();
+    }
+
+    let mut emitted: U256 = U256::from(0);
+
+    for (index, (segment_start, reward_rate)) in emission_schedule.iter().enumerate() {
+        let segment_end: U256 = emission_schedule
+            .get(index + 1)
+            .map_or(U256::MAX, |(next_start, _)| *next_start);
+
+        // part of the segment between `from` and `to`
+        let start: U256 = from.max(*segment_start);
+        let end: U256 = to.min(segment_end);
+
+        if end > start {
+            emitted = reward_rate
+                .checked_mul(end - start)
+                .and_then(|segment_reward| emitted.checked_add(segment_reward))
+                .ok_or(StakingError::Overflow).unwrap_or_revert();
+        }
+    }
+
+    emitted
+}
+
+#[no_mangle]
+pub extern "C" fn set_stake_token() {
+
+    only_owner();
+
+    let stake_token_key: Key = runtime::get_named_arg(STAKE_TOKEN_HASH_KEY_NAME);
+
+    // Staked and unbonding tokens could not be withdrawn from another token contract
+    let total_supply: U256 = get_key(TOTAL_SUPPLY_KEY_NAME).unwrap_or_revert();
+    let total_unbonding: U256 = get_key(TOTAL_UNBONDING_KEY_NAME).unwrap_or_revert();
+    if !total_supply.is_zero() || !total_unbonding.is_zero() {
+        runtime::revert(StakingError::PoolNotEmpty);
+    }
+
+    let stake_token_decimals: u8 = erc20_decimals(stake_token_key);
+
+    for slot in 0..reward_token_count() {
+        update_reward_per_token_stored(slot);
+    }
+
+    set_key(STAKE_TOKEN_HASH_KEY_NAME, stake_token_key);
+    set_key(STAKE_TOKEN_DECIMALS_KEY_NAME, stake_token_decimals);
+
+    update_compounding_enabled();
+    when_penalty_payable();
+}
+
+/// Replaces `reward_token` by the token in "reward_token_hash" argument,
+/// rewards already credited are paid in the new token
+#[no_mangle]
+pub extern "C" fn set_reward_token() {
+
+    only_owner();
+
+    let reward_token: Key = runtime::get_named_arg(REWARD_TOKEN_KEY_NAME);
+    let reward_token_key: Key = runtime::get_named_arg(REWARD_TOKEN_HASH_KEY_NAME);
+
+    let slot: usize = reward_slot(reward_token);
+
+    let mut reward_tokens: Vec<Key> = get_key(REWARD_TOKENS_KEY_NAME).unwrap_or_revert();
+    if reward_tokens.contains(&reward_token_key) {
+        runtime::revert(StakingError::RewardTokenExists);
+    }
+
+    when_not_stake_token(reward_token_key);
+
+    // Running period was funded with the current Reward Token
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+    let period_finish: U256 = get_key(&reward_key_name(PERIOD_FINISH_KEY_NAME, slot)).unwrap_or_revert();
+    if period_finish > current_block_time {
+        runtime::revert(StakingError::RewardPeriodActive);
+    }
+
+    // Escrowed rewards are claimed in the token they were taken from the reserve in
+    let escrowed_rewards: U256 = get_key(&reward_key_name(ESCROWED_REWARDS_KEY_NAME, slot)).unwrap_or_revert();
+    if !escrowed_rewards.is_zero() {
+        runtime::revert(StakingError::RewardsEscrowed);
+    }
+
+    let reward_token_decimals: u8 = erc20_decimals(reward_token_key);
+
+    update_reward_per_token_stored(slot);
+
+    set_key(&reward_key_name(REWARD_TOKEN_HASH_KEY_NAME, slot), reward_token_key);
+    set_key(&reward_key_name(REWARD_TOKEN_DECIMALS_KEY_NAME, slot), reward_token_decimals);
+
+    // Reserve was held in the previous token, rewards still owed wait for 'fund_rewards'.
+    // Previous token can be taken back with 'recover_tokens'
+    set_key(&reward_key_name(REWARD_RESERVE_KEY_NAME, slot), U256::from(0));
+
+    reward_tokens[slot] = reward_token_key;
+    set_key(REWARD_TOKENS_KEY_NAME, reward_tokens);
+
+    update_compounding_enabled();
+    when_penalty_payable();
+}
+
+/// Registers another token paid as reward to the stakers, with its own rate and reward periods.
+/// It is funded with `notify_reward_amount` like the first one
+#[no_mangle]
+pub extern "C" fn add_reward_token() {
+
+    only_owner();
+
+    let reward_token: Key = runtime::get_named_arg(REWARD_TOKEN_KEY_NAME);
+
+    let mut reward_tokens: Vec<Key> = get_key(REWARD_TOKENS_KEY_NAME).unwrap_or_revert();
+
+    if reward_tokens.contains(&reward_token) {
+        runtime::revert(StakingError::RewardTokenExists);
+    }
+
+    if reward_tokens.len() >= MAX_REWARD_TOKENS {
+        runtime::revert(StakingError::TooManyRewardTokens);
+    }
+
+    when_not_stake_token(reward_token);
+
+    let reward_token_decimals: u8 = erc20_decimals(reward_token);
+
+    let slot: usize = reward_tokens.len();
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+
+    // Same initial state as the first reward token at install, no period is running
+    set_key(&reward_key_name(REWARD_TOKEN_HASH_KEY_NAME, slot), reward_token);
+    set_key(&reward_key_name(REWARD_TOKEN_DECIMALS_KEY_NAME, slot), reward_token_decimals);
+    set_key(&reward_key_name(REWARD_RATE_KEY_NAME, slot), U256::from(0));
+    set_key(&reward_key_name(EMISSION_SCHEDULE_KEY_NAME, slot), Vec::<(U256, U256)>::new());
+    set_key(&reward_key_name(LAST_UPDATE_KEY_NAME, slot), current_block_time);
+    set_key(&reward_key_name(PERIOD_FINISH_KEY_NAME, slot), current_block_time);
+    set_key(&reward_key_name(REWARD_PER_TOKEN_STORED_KEY_NAME, slot), U256::from(0));
+    set_key(&reward_key_name(REWARD_PER_TOKEN_DUST_KEY_NAME, slot), U256::from(0));
+    set_key(&reward_key_name(REWARD_RESERVE_KEY_NAME, slot), U256::from(0));
+    set_key(&reward_key_name(OUTSTANDING_REWARDS_KEY_NAME, slot), U256::from(0));
+    set_key(&reward_key_name(ESCROWED_REWARDS_KEY_NAME, slot), U256::from(0));
+
+    storage::new_dictionary(&reward_key_name(REWARDS_KEY_NAME, slot)).unwrap_or_revert();
+    storage::new_dictionary(&reward_key_name(USER_REWARD_PER_TOKEN_PAID_KEY_NAME, slot)).unwrap_or_revert();
+    storage::new_dictionary(&reward_key_name(VESTING_ESCROWS_KEY_NAME, slot)).unwrap_or_revert();
+    storage::new_dictionary(&reward_key_name(VESTED_REWARDS_KEY_NAME, slot)).unwrap_or_revert();
+
+    reward_tokens.push(reward_token);
+    set_key(REWARD_TOKENS_KEY_NAME, reward_tokens);
+}
+
+/// Reverts if `token` is the Stake Token. A pool installed with one token for both keeps
+/// it, a reward token added or swapped in later would pay from the balance holding the stakes
+fn when_not_stake_token(token: Key) {
+    let stake_token: Key = get_key(STAKE_TOKEN_HASH_KEY_NAME).unwrap_or_revert();
+
+    if token == stake_token {
+        runtime::revert(StakingError::RewardTokenIsStakeToken);
+    }
+}
+
+/// Number of reward tokens, slots of "reward_tokens" go from 0 to this number - 1
+fn reward_token_count() -> usize {
+    let reward_tokens: Vec<Key> = get_key(REWARD_TOKENS_KEY_NAME).unwrap_or_revert();
+
+    reward_tokens.len()
+}
+
+/// Slot of `reward_token` in "reward_tokens"
+fn reward_slot(reward_token: Key) -> usize {
+    let reward_tokens: Vec<Key> = get_key(REWARD_TOKENS_KEY_NAME).unwrap_or_revert();
+
+    reward_tokens
+        .iter()
+        .position(|token| *token == reward_token)
+        .ok_or(StakingError::UnknownRewardToken).unwrap_or_revert()
+}
+
+/// Rewards the running period of `slot` has not distributed yet
+fn undistributed_rewards(slot: usize) -> U256 {
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+    let period_finish: U256 = get_key(&reward_key_name(PERIOD_FINISH_KEY_NAME, slot)).unwrap_or_revert();
+
+    if period_finish <= current_block_time {
+        return U256::from(0);
+    }
+
+    emitted_rewards(slot, current_block_time, period_finish)
+}
+
+/// Replaces the `undistributed` rewards of the running period in `slot` by `promised` ones.
+/// The reserve has to cover them on top of the rewards already owed to stakers
+fn promise_rewards(slot: usize, undistributed: U256, promised: U256) {
+    let outstanding_rewards_key_name: String = reward_key_name(OUTSTANDING_REWARDS_KEY_NAME, slot);
+    let outstanding_rewards: U256 = get_key(&outstanding_rewards_key_name).unwrap_or_revert();
+    let reward_reserve: U256 = get_key(&reward_key_name(REWARD_RESERVE_KEY_NAME, slot)).unwrap_or_revert();
+
+    let outstanding_rewards: U256 = outstanding_rewards
+        .checked_sub(undistributed)
+        .and_then(|owed| owed.checked_add(promised))
+        .ok_or(StakingError::Overflow).unwrap_or_revert();
+
+    if outstanding_rewards > reward_reserve {
+        runtime::revert(StakingError::RewardTooHigh);
+    }
+
+    set_key(&outstanding_rewards_key_name, outstanding_rewards);
+}
+
+/// Takes up to `amount` out of the reserve of `slot` to pay owed rewards,
+/// returns the part the reserve could cover
+fn take_from_reward_reserve(slot: usize, amount: U256) -> U256 {
+    let reward_reserve_key_name: String = reward_key_name(REWARD_RESERVE_KEY_NAME, slot);
+    let reward_reserve: U256 = get_key(&reward_reserve_key_name).unwrap_or_revert();
+
+    let amount: U256 = amount.min(reward_reserve);
+
+    set_key(&reward_reserve_key_name, reward_reserve - amount);
+    named_key_sub(amount, &reward_key_name(OUTSTANDING_REWARDS_KEY_NAME, slot));
+
+    amount
+}
+
+/// Compounding follows the Stake Token and the first Reward Token when the owner replaces them.
+/// Restaked rewards would skip the escrow, so a vesting period keeps it disabled
+fn update_compounding_enabled() {
+    let stake_token: Key = get_key(STAKE_TOKEN_HASH_KEY_NAME).unwrap_or_revert();
+    let reward_token: Key = get_key(REWARD_TOKEN_HASH_KEY_NAME).unwrap_or_revert();
+    let vesting_period: U256 = get_key(VESTING_PERIOD_KEY_NAME).unwrap_or_revert();
+
+    set_key(COMPOUNDING_ENABLED_KEY_NAME, stake_token == reward_token && vesting_period.is_zero());
+}
+
+/// Dictionary `name` of the reward token in `slot`
+fn reward_dictionary_uref(name: &str, slot: usize) -> URef {
+    let dictionary_key: Key = runtime::get_key(&reward_key_name(name, slot)).unwrap_or_revert();
+
+    dictionary_key.into_uref().unwrap_or_revert()
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_ownership() {
+
+    only_owner();
+
+    let new_owner: Address = runtime::get_named_arg(NEW_OWNER_KEY_NAME);
+
+    // New owner has to accept the ownership before it is transferred
+    set_key(PENDING_OWNER_KEY_NAME, Some(new_owner));
+}
+
+#[no_mangle]
+pub extern "C" fn accept_ownership() {
+
+    let caller: Address = get_immediate_caller_address().unwrap_or_revert();
+    let pending_owner: Option<Address> = get_key(PENDING_OWNER_KEY_NAME).unwrap_or_revert();
+
+    if pending_owner != Some(caller) {
+        runtime::revert(StakingError::Unauthorized);
+    }
+
+    set_key(OWNER_KEY_NAME, caller);
+    set_key::<Option<Address>>(PENDING_OWNER_KEY_NAME, None);
+}
+
+#[no_mangle]
+pub extern "C" fn pause() {
+
+    only_owner();
+
+    set_key(PAUSED_KEY_NAME, true);
+}
+
+#[no_mangle]
+pub extern "C" fn unpause() {
+
+    only_owner();
+
+    set_key(PAUSED_KEY_NAME, false);
+}
+
+fn is_paused() -> bool {
+    get_key(PAUSED_KEY_NAME).unwrap_or_revert()
+}
+
+/// Reverts while the contract is paused
+fn when_not_paused() {
+    if is_paused() {
+        runtime::revert(StakingError::Paused);
+    }
+}
+
+/// Sets the minimum stake, the per-address cap and the pool cap, 0 disables each of them.
+/// Lowered caps only stop new stake, balances above them are kept
+#[no_mangle]
+pub extern "C" fn set_stake_limits() {
+
+    only_owner();
+
+    let min_stake: U256 = runtime::get_named_arg(MIN_STAKE_KEY_NAME);
+    let max_stake_per_address: U256 = runtime::get_named_arg(MAX_STAKE_PER_ADDRESS_KEY_NAME);
+    let pool_cap: U256 = runtime::get_named_arg(POOL_CAP_KEY_NAME);
+
+    set_key(MIN_STAKE_KEY_NAME, min_stake);
+    set_key(MAX_STAKE_PER_ADDRESS_KEY_NAME, max_stake_per_address);
+    set_key(POOL_CAP_KEY_NAME, pool_cap);
+}
+
+/// Reverts unless a deposit of `amount` is at least the minimum stake
+/// and keeps `staker` and the pool within their caps
+fn when_within_stake_limits(staker: Address, amount: U256) {
+    let min_stake: U256 = get_key(MIN_STAKE_KEY_NAME).unwrap_or_revert();
+
+    if amount < min_stake {
+        runtime::revert(StakingError::BelowMinimumStake);
+    }
+
+    when_within_stake_caps(staker, amount);
+}
+
+fn when_within_stake_caps(staker: Address, amount: U256) {
+    if amount > remaining_address_capacity(staker) {
+        runtime::revert(StakingError::StakeCapExceeded);
+    }
+
+    if amount > remaining_pool_capacity() {
+        runtime::revert(StakingError::PoolCapExceeded);
+    }
+}
+
+/// Amount `address` can still stake under the per-address cap
+fn remaining_address_capacity(address: Address) -> U256 {
+    let max_stake_per_address: U256 = get_key(MAX_STAKE_PER_ADDRESS_KEY_NAME).unwrap_or_revert();
+
+    if max_stake_per_address.is_zero() {
+        return U256::MAX;
+    }
+
+    let balances_key: Key = runtime::get_key(BALANCES_KEY_NAME).unwrap_or_revert();
+    let balances_uref: URef = balances_key.into_uref().unwrap_or_revert();
+
+    max_stake_per_address.saturating_sub(dictionary_read(balances_uref, address))
+}
+
+/// Amount the pool can still take under the pool cap
+fn remaining_pool_capacity() -> U256 {
+    let pool_cap: U256 = get_key(POOL_CAP_KEY_NAME).unwrap_or_revert();
+
+    if pool_cap.is_zero() {
+        return U256::MAX;
+    }
+
+    let total_supply: U256 = get_key(TOTAL_SUPPLY_KEY_NAME).unwrap_or_revert();
+
+    pool_cap.saturating_sub(total_supply)
+}
+
+/// Lets `addresses` stake while the allowlist is enabled
+#[no_mangle]
+pub extern "C" fn add_to_allowlist() {
+
+    only_owner();
+
+    let addresses: Vec<Address> = runtime::get_named_arg(ADDRESSES_KEY_NAME);
+
+    set_allowlisted(addresses, true);
+}
+
+/// Stops `addresses` from staking more while the allowlist is enabled.
+/// Their stake and rewards can still be withdrawn
+#[no_mangle]
+pub extern "C" fn remove_from_allowlist() {
+
+    only_owner();
+
+    let addresses: Vec<Address> = runtime::get_named_arg(ADDRESSES_KEY_NAME);
+
+    set_allowlisted(addresses, false);
+}
+
+#[no_mangle]
+pub extern "C" fn set_allowlist_enabled() {
+
+    only_owner();
+
+    let allowlist_enabled: bool = runtime::get_named_arg(ALLOWLIST_ENABLED_KEY_NAME);
+
+    set_key(ALLOWLIST_ENABLED_KEY_NAME, allowlist_enabled);
+}
+
+fn set_allowlisted(addresses: Vec<Address>, allowed: bool) {
+    let allowlist_key: Key = runtime::get_key(ALLOWLIST_KEY_NAME).unwrap_or_revert();
+    let allowlist_uref: URef = allowlist_key.into_uref().unwrap_or_revert();
+
+    for address in addresses {
+        storage::dictionary_put(allowlist_uref, &make_dictionary_item_key(address), allowed);
+    }
+}
+
+fn allowlisted(address: Address) -> bool {
+    let allowlist_key: Key = runtime::get_key(ALLOWLIST_KEY_NAME).unwrap_or_revert();
+    let allowlist_uref: URef = allowlist_key.into_uref().unwrap_or_revert();
+
+    dictionary_get(allowlist_uref, &make_dictionary_item_key(address)).unwrap_or(false)
+}
+
+/// Reverts when the allowlist is enabled and `staker` is not on it.
+/// Only new stake is checked, withdrawals and rewards never are
+fn when_allowlisted(staker: Address) {
+    let allowlist_enabled: bool = get_key(ALLOWLIST_ENABLED_KEY_NAME).unwrap_or_revert();
+
+    if allowlist_enabled && !allowlisted(staker) {
+        runtime::revert(StakingError::NotAllowlisted);
+    }
+}
+
+/// Brings the stored state up to `CONTRACT_VERSION`, called by the upgrade session
+/// right after a new version is added. Does nothing when the state is already current
+#[no_mangle]
+pub extern "C" fn migrate() {
+
+    only_owner();
+
+    let stored_version: u32 = get_key(CONTRACT_VERSION_KEY_NAME).unwrap_or_revert();
+
+    if stored_version > CONTRACT_VERSION {
+        runtime::revert(StakingError::InvalidContractVersion);
+    }
+
+    // Schema changes go here, one block per version, applied in order:
+    // if stored_version < 2 { ... }
+
+    set_key(CONTRACT_VERSION_KEY_NAME, CONTRACT_VERSION);
+}
+
+/// Reverts unless the immediate caller is the owner of the contract
+fn only_owner() {
+    let caller: Address = get_immediate_caller_address().unwrap_or_revert();
+    let owner: Address = get_key(OWNER_KEY_NAME).unwrap_or_revert();
+
+    if caller != owner {
+        runtime::revert(StakingError::Unauthorized);
+    }
+}
+
+// Views, results are returned to the caller with `runtime::ret`
+
+#[no_mangle]
+pub extern "C" fn balance_of() {
+
+    let address: Address = runtime::get_named_arg(ADDRESS_KEY_NAME);
+    let balances_key: Key = runtime::get_key(BALANCES_KEY_NAME).unwrap_or_revert();
+    let balances_uref: URef = balances_key.into_uref().unwrap_or_revert();
+
+    let balance: U256 = dictionary_read(balances_uref, address);
+
+    runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
+}
+
+/// Rewards of `reward_token` `address` can claim at the current block time, nothing is written
+#[no_mangle]
+pub extern "C" fn earned() {
+
+    let address: Address = runtime::get_named_arg(ADDRESS_KEY_NAME);
+    let reward_token: Key = runtime::get_named_arg(REWARD_TOKEN_KEY_NAME);
+    let slot: usize = reward_slot(reward_token);
+
+    let (reward_per_token, _) = reward_per_token_at(slot, time_reward_applicable(slot));
+    let user_reward_per_token_paid: U256 = dictionary_read(
+        reward_dictionary_uref(USER_REWARD_PER_TOKEN_PAID_KEY_NAME, slot),
+        address
+    );
+
+    let earned: U256 = dictionary_read(reward_dictionary_uref(REWARDS_KEY_NAME, slot), address)
+        .checked_add(accrued_reward(address, reward_per_token, user_reward_per_token_paid))
+        .ok_or(StakingError::Overflow).unwrap_or_revert();
+
+    runtime::ret(CLValue::from_t(earned).unwrap_or_revert());
+}
 
-use crate::constants::{
-    STAKING_CONTRACT_KEY_NAME, REWARD_TOKEN_HASH_KEY_NAME, STAKE_TOKEN_HASH_KEY_NAME,
-    REWARD_RATE_KEY_NAME, TOTAL_SUPPLY_KEY_NAME, AMOUNT_KEY_NAME, BALANCES_KEY_NAME,
-    REWARDS_KEY_NAME, USER_REWARD_PER_TOKEN_PAID_KEY_NAME, LAST_UPDATE_KEY_NAME,
-    REWARD_PER_TOKEN_STORED_KEY_NAME,
-};
+/// Current value of the running sum, scaled by REWARD_PRECISION
+#[no_mangle]
+pub extern "C" fn reward_per_token() {
 
-use alloc::string::String;
+    let reward_token: Key = runtime::get_named_arg(REWARD_TOKEN_KEY_NAME);
+    let slot: usize = reward_slot(reward_token);
 
-use casper_erc20::{ Error, Address,
-    constants::{
-        TRANSFER_ENTRY_POINT_NAME, TRANSFER_FROM_ENTRY_POINT_NAME, OWNER_RUNTIME_ARG_NAME,
-        RECIPIENT_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME}
+    let (reward_per_token, _) = reward_per_token_at(slot, time_reward_applicable(slot));
+
+    runtime::ret(CLValue::from_t(reward_per_token).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn total_supply() {
+
+    let total_supply: U256 = get_key(TOTAL_SUPPLY_KEY_NAME).unwrap_or_revert();
+
+    runtime::ret(CLValue::from_t(total_supply).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn reward_rate() {
+
+    let reward_token: Key = runtime::get_named_arg(REWARD_TOKEN_KEY_NAME);
+    let slot: usize = reward_slot(reward_token);
+
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+    let reward_rate: U256 = reward_rate_at(slot, current_block_time);
+
+    runtime::ret(CLValue::from_t(reward_rate).unwrap_or_revert());
+}
+
+/// (current rate, next rate, start time of the next rate) of the reward token.
+/// Rates drop to 0 at the end of the period, which is the last change
+#[no_mangle]
+pub extern "C" fn emission_rates() {
+
+    let reward_token: Key = runtime::get_named_arg(REWARD_TOKEN_KEY_NAME);
+    let slot: usize = reward_slot(reward_token);
+
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+    let period_finish: U256 = get_key(&reward_key_name(PERIOD_FINISH_KEY_NAME, slot)).unwrap_or_revert();
+    let emission_schedule: Vec<(U256, U256)> = get_key(&reward_key_name(EMISSION_SCHEDULE_KEY_NAME, slot)).unwrap_or_revert();
+
+    let current_rate: U256 = if current_block_time < period_finish {
+        reward_rate_at(slot, current_block_time)
+    } else {
+        U256::from(0)
     };
 
-use casper_contract::{contract_api::{runtime, storage}, unwrap_or_revert::UnwrapOrRevert};
-use casper_types::{
-    contracts::{NamedKeys}, U256, ContractHash, Key, URef, RuntimeArgs, runtime_args, HashAddr};
+    let (next_start, next_rate) = emission_schedule
+        .into_iter()
+        .find(|(segment_start, _)| *segment_start > current_block_time && *segment_start < period_finish)
+        .unwrap_or((period_finish, U256::from(0)));
+
+    runtime::ret(CLValue::from_t((current_rate, next_rate, next_start)).unwrap_or_revert());
+}
 
 #[no_mangle]
-fn call() {
-    
-    let contract_name: String = runtime::get_named_arg(STAKING_CONTRACT_KEY_NAME);
-    let contract_hash_key_name = String::from(contract_name.clone());
-    let contract_package_hash_key_name = String::from(contract_name.clone() + "_package_hash");
-    
-    let stake_token_key: Key = runtime::get_named_arg(STAKE_TOKEN_HASH_KEY_NAME);
-    let reward_token_key: Key = runtime::get_named_arg(REWARD_TOKEN_HASH_KEY_NAME);
+pub extern "C" fn last_time_reward_applicable() {
 
-    let reward_rate: U256 = runtime::get_named_arg(REWARD_RATE_KEY_NAME);
+    let reward_token: Key = runtime::get_named_arg(REWARD_TOKEN_KEY_NAME);
+    let slot: usize = reward_slot(reward_token);
 
-    // TODO Check that Reward Token and Stake Token are existing ERC20 contracts
+    runtime::ret(CLValue::from_t(time_reward_applicable(slot)).unwrap_or_revert());
+}
 
-    let named_keys: NamedKeys = named_keys::default(
-        contract_name,
-        stake_token_key,
-        reward_token_key,
-        reward_rate
-    );
-    
-    // We store contract on-chain
-    let (contract_hash, _version) = storage::new_locked_contract(
-        entry_points::default(),
-        Some(named_keys),
-        Some(String::from(contract_package_hash_key_name)),
-        None
-    );
+/// Stake of `address` weighted by its lock multipliers
+#[no_mangle]
+pub extern "C" fn effective_balance_of() {
 
-    runtime::put_key(contract_hash_key_name.as_str(), Key::from(contract_hash));
+    let address: Address = runtime::get_named_arg(ADDRESS_KEY_NAME);
+    let effective_balances_key: Key = runtime::get_key(EFFECTIVE_BALANCES_KEY_NAME).unwrap_or_revert();
+    let effective_balances_uref: URef = effective_balances_key.into_uref().unwrap_or_revert();
+
+    let effective_balance: U256 = dictionary_read(effective_balances_uref, address);
+
+    runtime::ret(CLValue::from_t(effective_balance).unwrap_or_revert());
 }
 
+/// Part of the stake of `address` held in lock positions
 #[no_mangle]
-pub extern "C" fn stake() {
-    
-    // TODO Frontend should check 'allowance' of ERC20 'Stake token' contract for user
-    // Let user to call 'approve' first, before staking
+pub extern "C" fn locked_balance_of() {
 
-    let amount: U256 = runtime::get_named_arg(AMOUNT_KEY_NAME);
+    let address: Address = runtime::get_named_arg(ADDRESS_KEY_NAME);
+    let locked_balances_key: Key = runtime::get_key(LOCKED_BALANCES_KEY_NAME).unwrap_or_revert();
+    let locked_balances_uref: URef = locked_balances_key.into_uref().unwrap_or_revert();
 
-    let staker: Address = get_immediate_caller_address().unwrap_or_revert();
-    let balances_key: Key = runtime::get_key(BALANCES_KEY_NAME).unwrap_or_revert();
-    let rewards_key: Key = runtime::get_key(REWARDS_KEY_NAME).unwrap_or_revert();
-    let balances_uref: URef = balances_key.into_uref().unwrap_or_revert();
-    let rewards_uref: URef = rewards_key.into_uref().unwrap_or_revert();
-    
-    update_reward(staker, balances_uref, rewards_uref);
-    
-    // update total_supply
-    named_key_add(amount, TOTAL_SUPPLY_KEY_NAME);
+    let locked_balance: U256 = dictionary_read(locked_balances_uref, address);
+
+    runtime::ret(CLValue::from_t(locked_balance).unwrap_or_revert());
+}
 
-    // update balance of caller
-    dictionary_add(balances_uref, staker, amount);
+/// (amount, unlock time) of every unbonding entry of `address` not claimed yet
+#[no_mangle]
+pub extern "C" fn unbonding_entries() {
 
-    // Transfer `amount` of Stake Token from caller to the stake contract
-    erc20_transfer_from(
-        STAKE_TOKEN_HASH_KEY_NAME,
-        staker,
-        amount
+    let address: Address = runtime::get_named_arg(ADDRESS_KEY_NAME);
+    let unbonding_entries_key: Key = runtime::get_key(UNBONDING_ENTRIES_KEY_NAME).unwrap_or_revert();
+    let unbonding_entries_uref: URef = unbonding_entries_key.into_uref().unwrap_or_revert();
+
+    let unbonding_entries: Vec<(U256, U256)> = dictionary_get(unbonding_entries_uref, &make_dictionary_item_key(address)).unwrap_or_default();
+
+    runtime::ret(CLValue::from_t(unbonding_entries).unwrap_or_revert());
+}
+
+/// (penalty period, penalty rate in basis points) of early exits
+#[no_mangle]
+pub extern "C" fn penalty_schedule() {
+
+    let penalty_period: U256 = get_key(PENALTY_PERIOD_KEY_NAME).unwrap_or_revert();
+    let penalty_rate: U256 = get_key(PENALTY_RATE_KEY_NAME).unwrap_or_revert();
+
+    runtime::ret(CLValue::from_t((penalty_period, penalty_rate)).unwrap_or_revert());
+}
+
+/// Receiver of early-exit penalties, `None` when they go to the stakers
+#[no_mangle]
+pub extern "C" fn treasury() {
+
+    let treasury: Option<Address> = get_key(TREASURY_KEY_NAME).unwrap_or_revert();
+
+    runtime::ret(CLValue::from_t(treasury).unwrap_or_revert());
+}
+
+/// Address allowed to call the admin entry points
+#[no_mangle]
+pub extern "C" fn owner() {
+
+    let owner: Address = get_key(OWNER_KEY_NAME).unwrap_or_revert();
+
+    runtime::ret(CLValue::from_t(owner).unwrap_or_revert());
+}
+
+/// Whether `address` is on the allowlist, it only restricts staking while enabled
+#[no_mangle]
+pub extern "C" fn is_allowlisted() {
+
+    let address: Address = runtime::get_named_arg(ADDRESS_KEY_NAME);
+
+    runtime::ret(CLValue::from_t(allowlisted(address)).unwrap_or_revert());
+}
+
+/// (minimum stake, per-address cap, pool cap), 0 disables each of them
+#[no_mangle]
+pub extern "C" fn stake_limits() {
+
+    let min_stake: U256 = get_key(MIN_STAKE_KEY_NAME).unwrap_or_revert();
+    let max_stake_per_address: U256 = get_key(MAX_STAKE_PER_ADDRESS_KEY_NAME).unwrap_or_revert();
+    let pool_cap: U256 = get_key(POOL_CAP_KEY_NAME).unwrap_or_revert();
+
+    runtime::ret(CLValue::from_t((min_stake, max_stake_per_address, pool_cap)).unwrap_or_revert());
+}
+
+/// Amount `address` can still stake under both caps, U256::MAX when neither is set
+#[no_mangle]
+pub extern "C" fn remaining_capacity() {
+
+    let address: Address = runtime::get_named_arg(ADDRESS_KEY_NAME);
+
+    let remaining_capacity: U256 = remaining_address_capacity(address).min(remaining_pool_capacity());
+
+    runtime::ret(CLValue::from_t(remaining_capacity).unwrap_or_revert());
+}
+
+/// Start of the early-exit penalty period of `address`: block times of its stakes averaged
+/// by the staked amounts, not the time of its last stake
+#[no_mangle]
+pub extern "C" fn weighted_stake_time() {
+
+    let address: Address = runtime::get_named_arg(ADDRESS_KEY_NAME);
+    let weighted_stake_times_key: Key = runtime::get_key(WEIGHTED_STAKE_TIMES_KEY_NAME).unwrap_or_revert();
+    let weighted_stake_times_uref: URef = weighted_stake_times_key.into_uref().unwrap_or_revert();
+
+    let weighted_stake_time: U256 = dictionary_read(weighted_stake_times_uref, address);
+
+    runtime::ret(CLValue::from_t(weighted_stake_time).unwrap_or_revert());
+}
+
+/// Escrowed rewards of `reward_token` of `address` not vested yet at the current block time
+#[no_mangle]
+pub extern "C" fn locked_rewards_of() {
+
+    let address: Address = runtime::get_named_arg(ADDRESS_KEY_NAME);
+    let reward_token: Key = runtime::get_named_arg(REWARD_TOKEN_KEY_NAME);
+    let slot: usize = reward_slot(reward_token);
+
+    let vesting_escrow: Vec<(U256, U256, U256)> = dictionary_get(
+        reward_dictionary_uref(VESTING_ESCROWS_KEY_NAME, slot),
+        &make_dictionary_item_key(address)
+    ).unwrap_or_default();
+
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+    let locked_rewards: U256 = vesting_escrow
+        .iter()
+        .fold(U256::zero(), |locked, (amount, _, _)| locked + *amount)
+        - escrow_vested_amount(&vesting_escrow, current_block_time);
+
+    runtime::ret(CLValue::from_t(locked_rewards).unwrap_or_revert());
+}
+
+/// Escrowed rewards of `reward_token` of `address` `claim_vested` would pay at the current block time
+#[no_mangle]
+pub extern "C" fn unlocked_rewards_of() {
+
+    let address: Address = runtime::get_named_arg(ADDRESS_KEY_NAME);
+    let reward_token: Key = runtime::get_named_arg(REWARD_TOKEN_KEY_NAME);
+    let slot: usize = reward_slot(reward_token);
+
+    let vesting_escrow: Vec<(U256, U256, U256)> = dictionary_get(
+        reward_dictionary_uref(VESTING_ESCROWS_KEY_NAME, slot),
+        &make_dictionary_item_key(address)
+    ).unwrap_or_default();
+
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+    let unlocked_rewards: U256 = dictionary_read(reward_dictionary_uref(VESTED_REWARDS_KEY_NAME, slot), address)
+        .checked_add(escrow_vested_amount(&vesting_escrow, current_block_time))
+        .ok_or(StakingError::Overflow).unwrap_or_revert();
+
+    runtime::ret(CLValue::from_t(unlocked_rewards).unwrap_or_revert());
+}
+
+/// Token locked to boost rewards, `None` while boosting is disabled
+#[no_mangle]
+pub extern "C" fn boost_token() {
+
+    let boost_token: Option<Key> = get_key(BOOST_TOKEN_HASH_KEY_NAME);
+
+    runtime::ret(CLValue::from_t(boost_token).unwrap_or_revert());
+}
+
+/// Boost Tokens locked by `address`
+#[no_mangle]
+pub extern "C" fn boost_balance_of() {
+
+    let address: Address = runtime::get_named_arg(ADDRESS_KEY_NAME);
+    let boost_balances_key: Key = runtime::get_key(BOOST_BALANCES_KEY_NAME).unwrap_or_revert();
+    let boost_balances_uref: URef = boost_balances_key.into_uref().unwrap_or_revert();
+
+    let boost_balance: U256 = dictionary_read(boost_balances_uref, address);
+
+    runtime::ret(CLValue::from_t(boost_balance).unwrap_or_revert());
+}
+
+/// Part of the effective balance of `address` earning rewards, as of its last checkpoint
+#[no_mangle]
+pub extern "C" fn working_balance_of() {
+
+    let address: Address = runtime::get_named_arg(ADDRESS_KEY_NAME);
+    let working_balances_key: Key = runtime::get_key(WORKING_BALANCES_KEY_NAME).unwrap_or_revert();
+    let working_balances_uref: URef = working_balances_key.into_uref().unwrap_or_revert();
+
+    let working_balance: U256 = dictionary_read(working_balances_uref, address);
+
+    runtime::ret(CLValue::from_t(working_balance).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn working_supply() {
+
+    let working_supply: U256 = get_key(WORKING_SUPPLY_KEY_NAME).unwrap_or_revert();
+
+    runtime::ret(CLValue::from_t(working_supply).unwrap_or_revert());
+}
+
+/// Stake of `address` at the end of `timestamp`, which has to be in the past
+#[no_mangle]
+pub extern "C" fn balance_of_at() {
+
+    let address: Address = runtime::get_named_arg(ADDRESS_KEY_NAME);
+    let timestamp: U256 = runtime::get_named_arg(TIMESTAMP_KEY_NAME);
+
+    when_snapshot_final(timestamp);
+
+    let balance_checkpoints_key: Key = runtime::get_key(BALANCE_CHECKPOINTS_KEY_NAME).unwrap_or_revert();
+    let balance_checkpoints_uref: URef = balance_checkpoints_key.into_uref().unwrap_or_revert();
+    let balance_checkpoint_counts_key: Key = runtime::get_key(BALANCE_CHECKPOINT_COUNTS_KEY_NAME).unwrap_or_revert();
+    let balance_checkpoint_counts_uref: URef = balance_checkpoint_counts_key.into_uref().unwrap_or_revert();
+
+    let balance_checkpoint_count: u32 = dictionary_get(balance_checkpoint_counts_uref, &make_dictionary_item_key(address)).unwrap_or_default();
+
+    let balance: U256 = checkpoint_at(
+        balance_checkpoints_uref,
+        balance_checkpoint_count,
+        |index| make_indexed_item_key(address, index),
+        timestamp
     );
 
+    runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
 }
 
+/// Total stake at the end of `timestamp`, which has to be in the past
 #[no_mangle]
-pub extern "C" fn withdraw() {
-    
-    let amount: U256 = runtime::get_named_arg(AMOUNT_KEY_NAME);
+pub extern "C" fn total_supply_at() {
 
-    let staker = get_immediate_caller_address().unwrap_or_revert();
-    let balances_key: Key = runtime::get_key(BALANCES_KEY_NAME).unwrap_or_revert();
-    let rewards_key: Key = runtime::get_key(REWARDS_KEY_NAME).unwrap_or_revert();
-    let balances_uref: URef = balances_key.into_uref().unwrap_or_revert();
-    let rewards_uref: URef = rewards_key.into_uref().unwrap_or_revert();
+    let timestamp: U256 = runtime::get_named_arg(TIMESTAMP_KEY_NAME);
 
-    update_reward(staker, balances_uref, rewards_uref);
+    when_snapshot_final(timestamp);
 
-    // update total_supply
-    named_key_sub(amount, TOTAL_SUPPLY_KEY_NAME);
+    let total_supply_checkpoints_key: Key = runtime::get_key(TOTAL_SUPPLY_CHECKPOINTS_KEY_NAME).unwrap_or_revert();
+    let total_supply_checkpoints_uref: URef = total_supply_checkpoints_key.into_uref().unwrap_or_revert();
 
-    // update balance of caller
-    dictionary_sub(balances_uref, staker, amount);
+    let total_supply_checkpoint_count: u32 = get_key(TOTAL_SUPPLY_CHECKPOINT_COUNT_KEY_NAME).unwrap_or_revert();
 
-    // Transfer `amount` of Stake Token from the stake contract to caller
-    erc20_transfer(
-        STAKE_TOKEN_HASH_KEY_NAME,
-        staker,
-        amount
+    let total_supply: U256 = checkpoint_at(
+        total_supply_checkpoints_uref,
+        total_supply_checkpoint_count,
+        |index| index.to_string(),
+        timestamp
     );
 
-    get_reward();
+    runtime::ret(CLValue::from_t(total_supply).unwrap_or_revert());
+}
+
+/// Every token paid as reward, in the order they were added
+#[no_mangle]
+pub extern "C" fn reward_tokens() {
+
+    let reward_tokens: Vec<Key> = get_key(REWARD_TOKENS_KEY_NAME).unwrap_or_revert();
 
+    runtime::ret(CLValue::from_t(reward_tokens).unwrap_or_revert());
 }
 
+/// Reward Tokens of `reward_token` held to pay rewards
 #[no_mangle]
-pub extern "C" fn get_reward() {
-    
-    let staker: Address = get_immediate_caller_address().unwrap_or_revert();
-    let balances_key: Key = runtime::get_key(BALANCES_KEY_NAME).unwrap_or_revert();
-    let rewards_key: Key = runtime::get_key(REWARDS_KEY_NAME).unwrap_or_revert();
-    let balances_uref: URef = balances_key.into_uref().unwrap_or_revert();
-    let rewards_uref: URef = rewards_key.into_uref().unwrap_or_revert();
+pub extern "C" fn reward_reserve() {
 
-    update_reward(staker, balances_uref, rewards_uref);
+    let reward_token: Key = runtime::get_named_arg(REWARD_TOKEN_KEY_NAME);
+    let slot: usize = reward_slot(reward_token);
 
-    // get reward_value of the caller stored in "rewards" dictionary
-    let staker_reward: U256 = dictionary_read(rewards_uref, staker);
-    
-    // set reward_value of the caller in the dictionary to 0
-    dictionary_write(rewards_uref, staker, U256::from(0));
+    let reward_reserve: U256 = get_key(&reward_key_name(REWARD_RESERVE_KEY_NAME, slot)).unwrap_or_revert();
 
-    // Transfer `amount` of Reward Token to caller
-    erc20_transfer(
-        REWARD_TOKEN_HASH_KEY_NAME,
-        staker,
-        staker_reward
-    );
+    runtime::ret(CLValue::from_t(reward_reserve).unwrap_or_revert());
+}
+
+/// Rewards of `reward_token` owed to stakers or still to distribute in the running period
+#[no_mangle]
+pub extern "C" fn outstanding_rewards() {
+
+    let reward_token: Key = runtime::get_named_arg(REWARD_TOKEN_KEY_NAME);
+    let slot: usize = reward_slot(reward_token);
+
+    let outstanding_rewards: U256 = get_key(&reward_key_name(OUTSTANDING_REWARDS_KEY_NAME, slot)).unwrap_or_revert();
+
+    runtime::ret(CLValue::from_t(outstanding_rewards).unwrap_or_revert());
+}
+
+/// Credits `staker` with the rewards of every reward token accrued since the last checkpoint
+fn update_reward(staker: Address) {
+    for slot in 0..reward_token_count() {
+        update_reward_for(staker, slot);
+    }
+
+    // boost changes of the other users and of the supply only apply from the checkpoint on
+    update_working_balance(staker);
 }
 
 #[no_mangle]
- fn update_reward(
+ fn update_reward_for(
     staker: Address,
-    balances_uref: URef,
-    rewards_uref: URef
+    slot: usize
  ) {
     
-    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
-    let user_reward_per_token_paid_key: Key = runtime::get_key(USER_REWARD_PER_TOKEN_PAID_KEY_NAME).unwrap_or_revert();
-    let user_reward_per_token_paid_uref: URef = user_reward_per_token_paid_key.into_uref().unwrap_or_revert();
+    let rewards_uref: URef = reward_dictionary_uref(REWARDS_KEY_NAME, slot);
+    let user_reward_per_token_paid_uref: URef = reward_dictionary_uref(USER_REWARD_PER_TOKEN_PAID_KEY_NAME, slot);
     let user_reward_per_token_paid: U256 = dictionary_read(user_reward_per_token_paid_uref, staker);
     
-    // update reward_per_token_stored
-    let reward_per_token_stored: U256 = reward_per_token(current_block_time);
-    
-    // update last_update_time
-    set_key(LAST_UPDATE_KEY_NAME, current_block_time);
+    // update reward_per_token_stored and last_update_time
+    let reward_per_token_stored: U256 = update_reward_per_token_stored(slot);
     
     // update reward amount of the staker
     dictionary_add(
         rewards_uref,
         staker,
-        earned(staker, balances_uref, user_reward_per_token_paid)
-    );
+        accrued_reward(staker, reward_per_token_stored, user_reward_per_token_paid)
+    ).unwrap_or_revert();
     
     // update "user_reward_per_token_paid" dictionary
     dictionary_write(user_reward_per_token_paid_uref, staker, reward_per_token_stored);
 }
 
-#[no_mangle]
-/// Computes the running sum of 'R' over 'total supply' of 'token stake'
-fn reward_per_token(current_block_time: U256) -> U256 {
+/// Checkpoints 'reward_per_token_stored' and 'last_update_time' of the reward token in `slot`
+/// up to the moment rewards are still applicable
+fn update_reward_per_token_stored(slot: usize) -> U256 {
+
+    let update_time: U256 = time_reward_applicable(slot);
+
+    // Nobody earns the rewards distributed while nothing is staked, they are not owed anymore
+    let working_supply: U256 = get_key(WORKING_SUPPLY_KEY_NAME).unwrap_or_revert();
+    if working_supply.is_zero() {
+        let last_update_time: U256 = get_key(&reward_key_name(LAST_UPDATE_KEY_NAME, slot)).unwrap_or_revert();
+        let unassigned: U256 = emitted_rewards(slot, last_update_time, update_time);
+
+        named_key_sub(unassigned, &reward_key_name(OUTSTANDING_REWARDS_KEY_NAME, slot));
+    }
+
+    let (reward_per_token_stored, reward_per_token_dust) = reward_per_token_at(slot, update_time);
+
+    set_key(&reward_key_name(REWARD_PER_TOKEN_STORED_KEY_NAME, slot), reward_per_token_stored);
+    set_key(&reward_key_name(REWARD_PER_TOKEN_DUST_KEY_NAME, slot), reward_per_token_dust);
+    set_key(&reward_key_name(LAST_UPDATE_KEY_NAME, slot), update_time);
+
+    reward_per_token_stored
+}
+
+/// Rewards only accrue until the end of the current reward period
+fn time_reward_applicable(slot: usize) -> U256 {
+
+    let current_block_time: U256 = U256::from(u64::from(runtime::get_blocktime()));
+    let period_finish: U256 = get_key(&reward_key_name(PERIOD_FINISH_KEY_NAME, slot)).unwrap_or_revert();
+
+    current_block_time.min(period_finish)
+}
+
+/// Computes the running sum of 'R' over 'working supply' of 'token stake' up to `time_reward_applicable`
+/// and the remainder of the division, without writing them
+fn reward_per_token_at(slot: usize, time_reward_applicable: U256) -> (U256, U256) {
     
-    let total_supply: U256 = get_key(TOTAL_SUPPLY_KEY_NAME).unwrap_or_revert();
-    let reward_per_token_stored: U256 = get_key(REWARD_PER_TOKEN_STORED_KEY_NAME).unwrap_or_revert();
+    let total_supply: U256 = get_key(WORKING_SUPPLY_KEY_NAME).unwrap_or_revert();
+    let reward_per_token_stored: U256 = get_key(&reward_key_name(REWARD_PER_TOKEN_STORED_KEY_NAME, slot)).unwrap_or_revert();
+    let reward_per_token_dust: U256 = get_key(&reward_key_name(REWARD_PER_TOKEN_DUST_KEY_NAME, slot)).unwrap_or_revert();
 
     if total_supply.is_zero() {
-        // TODO implement Error for runtime::revert()
         // TODO Brainstorm: Return 0 or current reward_per_token_stored
-        reward_per_token_stored
+        (reward_per_token_stored, reward_per_token_dust)
     } else {
         
-        let reward_rate: U256 = get_key(REWARD_RATE_KEY_NAME).unwrap_or_revert();
-        let last_update_time: U256 = get_key(LAST_UPDATE_KEY_NAME).unwrap_or_revert();
-        
+        let last_update_time: U256 = get_key(&reward_key_name(LAST_UPDATE_KEY_NAME, slot)).unwrap_or_revert();
+
+        // rewards distributed since the last update, across the segments of the emission schedule,
+        // scaled by REWARD_PRECISION, plus whatever could not be distributed last time
+        let scaled_reward: U256 = emitted_rewards(slot, last_update_time, time_reward_applicable)
+            .checked_mul(U256::from(REWARD_PRECISION))
+            .and_then(|reward| reward.checked_add(reward_per_token_dust))
+            .ok_or(StakingError::Overflow).unwrap_or_revert();
+
         let new_value: U256 = {
             reward_per_token_stored
-                // TODO rework time operations
-                .checked_add(reward_rate * ( current_block_time - last_update_time ) / total_supply)
-                .ok_or(Error::Overflow).unwrap_or_revert()
+                .checked_add(scaled_reward / total_supply)
+                .ok_or(StakingError::Overflow).unwrap_or_revert()
         };
-    
-        set_key(REWARD_PER_TOKEN_STORED_KEY_NAME, new_value);
 
-        new_value
+        // remainder of the division is carried over to the next update instead of being lost
+        (new_value, scaled_reward % total_supply)
 
     }
 }
 
-#[no_mangle]
-/// Amount of Rewads tokens user can claim so far
-fn earned(
+/// Amount of Rewads tokens user accrued since the last checkpoint
+fn accrued_reward(
     staker: Address,
-    balances_uref: URef,
+    reward_per_token: U256,
     user_reward_per_token_paid: U256
 ) -> U256 {
     
-    let balance: U256 = dictionary_read(balances_uref, staker);
-    let reward_per_token_stored: U256 = get_key(REWARD_PER_TOKEN_STORED_KEY_NAME).unwrap_or_revert();
+    let working_balances_key: Key = runtime::get_key(WORKING_BALANCES_KEY_NAME).unwrap_or_revert();
+    let working_balances_uref: URef = working_balances_key.into_uref().unwrap_or_revert();
+    let balance: U256 = dictionary_read(working_balances_uref, staker);
 
-    balance * ( reward_per_token_stored - user_reward_per_token_paid )
+    // reward per token is scaled by REWARD_PRECISION
+    balance * ( reward_per_token - user_reward_per_token_paid ) / U256::from(REWARD_PRECISION)
     
 }
 
@@ -228,7 +2435,7 @@ fn dictionary_add(
     dictionary_uref: URef,
     staker: Address,
     amount: U256,
-) -> Result<(), Error> {
+) -> Result<(), StakingError> {
     if amount.is_zero() {
         return Ok(());
     }
@@ -237,7 +2444,7 @@ fn dictionary_add(
         let staker_balance = dictionary_read(dictionary_uref, staker);
         staker_balance
             .checked_add(amount)
-            .ok_or(Error::Overflow)?
+            .ok_or(StakingError::Overflow)?
     };
 
     dictionary_write(dictionary_uref, staker, new_staker_balance);
@@ -249,7 +2456,7 @@ fn dictionary_sub(
     dictionary_uref: URef,
     staker: Address,
     amount: U256,
-) -> Result<(), Error> {
+) -> Result<(), StakingError> {
     if amount.is_zero() {
         return Ok(());
     }
@@ -258,7 +2465,7 @@ fn dictionary_sub(
         let staker_balance = dictionary_read(dictionary_uref, staker);
         staker_balance
             .checked_sub(amount)
-            .ok_or(Error::InsufficientBalance)?
+            .ok_or(StakingError::InsufficientStake)?
     };
 
     dictionary_write(dictionary_uref, staker, new_staker_balance);
@@ -272,7 +2479,7 @@ fn named_key_add(amount: U256, key_name: &str) {
         let current_value: U256 = get_key(key_name).unwrap_or_revert();
         current_value
             .checked_add(amount)
-            .ok_or(Error::Overflow).unwrap_or_revert()
+            .ok_or(StakingError::Overflow).unwrap_or_revert()
     };
 
     set_key(key_name, new_value);
@@ -285,26 +2492,68 @@ fn named_key_sub(amount: U256, key_name: &str) {
         let current_value: U256 = get_key(key_name).unwrap_or_revert();
         current_value
             .checked_sub(amount)
-            .ok_or(Error::InsufficientBalance).unwrap_or_revert()
+            .ok_or(StakingError::InsufficientStake).unwrap_or_revert()
     };
 
     set_key(key_name, new_value);
 
 }
 
+/// Reads the 'Contract Hash' of an ERC20 token stored under `erc20_hash_key_name`
+fn erc20_contract_hash(erc20_hash_key_name: &str) -> ContractHash {
+    let erc20_contract_key: Key = runtime::get_key(erc20_hash_key_name).unwrap_or_revert();
+    let erc20_contract_uref: URef = erc20_contract_key.into_uref().unwrap_or_revert();
+    let erc20_contract_hash_key: Key =  storage::read(erc20_contract_uref).unwrap_or_revert().unwrap_or_revert();
+    let erc20_contract_hash_addr: HashAddr  = erc20_contract_hash_key.into_hash().unwrap_or_revert();
+
+    ContractHash::new(erc20_contract_hash_addr)
+}
+
+/// Checks that `token` is an ERC20 contract and returns its decimals.
+/// A key that is not a contract hash reverts with `InvalidTokenContract`. The token contract is
+/// not trusted yet, so only read-only entry points are called: "balance_of" and "allowance" read
+/// the balances "transfer" and "transfer_from" move, then "decimals". A contract can't list the
+/// entry points of another one, a contract missing one of them fails the call in the engine
+fn erc20_decimals(token: Key) -> u8 {
+    let erc20_contract_hash: ContractHash = token
+        .into_hash()
+        .map(ContractHash::new)
+        .ok_or(StakingError::InvalidTokenContract)
+        .unwrap_or_revert();
+
+    let self_addr: Address = get_self_address().unwrap_or_revert();
+
+    let _: U256 = runtime::call_contract(erc20_contract_hash, BALANCE_OF_ENTRY_POINT_NAME, runtime_args!{
+        ADDRESS_RUNTIME_ARG_NAME => self_addr
+    });
+
+    let _: U256 = runtime::call_contract(erc20_contract_hash, ALLOWANCE_ENTRY_POINT_NAME, runtime_args!{
+        OWNER_RUNTIME_ARG_NAME => self_addr,
+        SPENDER_RUNTIME_ARG_NAME => self_addr
+    });
+
+    runtime::call_contract(erc20_contract_hash, DECIMALS_ENTRY_POINT_NAME, runtime_args!{})
+}
+
 fn erc20_transfer_from(
     erc20_hash_key_name: &str,
     staker: Address,
     amount: U256
 ) {
-    let erc20_contract_key: Key = runtime::get_key(erc20_hash_key_name).unwrap_or_revert();
-    let erc20_contract_uref: URef = erc20_contract_key.into_uref().unwrap_or_revert();
-    let erc20_contract_hash_key: Key =  storage::read(erc20_contract_uref).unwrap_or_revert().unwrap_or_revert();
-    let erc20_contract_hash_addr: HashAddr  = erc20_contract_hash_key.into_hash().unwrap_or_revert();
-    let erc20_contract_hash: ContractHash = ContractHash::new(erc20_contract_hash_addr);
+    let erc20_contract_hash: ContractHash = erc20_contract_hash(erc20_hash_key_name);
     
     let self_addr = get_self_address().unwrap_or_revert();
 
+    // Caller has to 'approve' the stake contract first
+    let allowance: U256 = runtime::call_contract(erc20_contract_hash, ALLOWANCE_ENTRY_POINT_NAME, runtime_args!{
+        OWNER_RUNTIME_ARG_NAME => staker,
+        SPENDER_RUNTIME_ARG_NAME => self_addr
+    });
+
+    if allowance < amount {
+        runtime::revert(StakingError::InsufficientAllowance);
+    }
+
     let _: () = runtime::call_contract(erc20_contract_hash, TRANSFER_FROM_ENTRY_POINT_NAME, runtime_args!{
         OWNER_RUNTIME_ARG_NAME => staker,
         RECIPIENT_RUNTIME_ARG_NAME => self_addr,
@@ -317,14 +2566,10 @@ fn erc20_transfer(
     staker: Address,
     amount: U256
 ) {
-    let erc20_contract_key: Key = runtime::get_key(erc20_hash_key_name).unwrap_or_revert();
-    let erc20_contract_uref: URef = erc20_contract_key.into_uref().unwrap_or_revert();
-    let erc20_contract_hash_key: Key =  storage::read(erc20_contract_uref).unwrap_or_revert().unwrap_or_revert();
-    let erc20_contract_hash_addr: HashAddr  = erc20_contract_hash_key.into_hash().unwrap_or_revert();
-    let erc20_contract_hash: ContractHash = ContractHash::new(erc20_contract_hash_addr);
+    let erc20_contract_hash: ContractHash = erc20_contract_hash(erc20_hash_key_name);
  
     runtime::call_contract(erc20_contract_hash, TRANSFER_ENTRY_POINT_NAME, runtime_args!{
         RECIPIENT_RUNTIME_ARG_NAME => staker,
         AMOUNT_RUNTIME_ARG_NAME => amount
     })
-}
\ No newline at end of file
+}
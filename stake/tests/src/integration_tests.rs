@@ -3,21 +3,30 @@ mod test_fixture;
 
 #[cfg(test)]
 mod tests {
-    use casper_types::{Key, U256};
+    use casper_types::{account::AccountHash, runtime_args, AsymmetricType, Key, PublicKey, RuntimeArgs, U256};
     use casper_erc20::{ Address };
 
-    use crate::test_fixture::{Sender, TestFixture};
+    use crate::test_fixture::{
+        Sender, TestFixture, UNBONDING_PERIOD_KEY_NAME, PENALTY_PERIOD_KEY_NAME, PENALTY_RATE_KEY_NAME,
+        TREASURY_KEY_NAME, VESTING_PERIOD_KEY_NAME, ALLOWLIST_ENABLED_KEY_NAME, ALLOWLIST_KEY_NAME,
+        MIN_STAKE_KEY_NAME, MAX_STAKE_PER_ADDRESS_KEY_NAME, POOL_CAP_KEY_NAME, STAKE_TOKEN_HASH_KEY_NAME,
+        REWARD_TOKEN_HASH_KEY_NAME
+    };
 
     #[test]
     fn should_install() {
         let fixture = TestFixture::install_contract();
 
-        assert_eq!(fixture.reward_rate(), U256::from(20));
+        assert_eq!(fixture.reward_rate(), U256::from(0));
+        assert_eq!(fixture.period_finish(), U256::from(0));
         assert_eq!(fixture.stake_token_hash(), Key::from(fixture.stake_contract_hash));
         assert_eq!(fixture.reward_token_hash(), Key::from(fixture.reward_contract_hash));
         assert_eq!(fixture.staking_contract_name(), fixture.contract_name);
         assert_eq!(fixture.reward_per_token_stored(), U256::from(0));
         assert_eq!(fixture.total_supply(), U256::from(0));
+        assert_eq!(fixture.owner(), Key::from(fixture.ali));
+        assert_eq!(fixture.pending_owner(), None);
+        assert!(!fixture.paused());
 
         // TODO Check 'Last_time_updated'
         
@@ -38,12 +47,9 @@ mod tests {
         let spender: Address = Address::from(fixture.staking_contract_package_hash);
         let recipient: Address = Address::from(fixture.staking_contract_package_hash);
 
-        // Send rewards tokens to Staking Contract
-        fixture.transfer_reward_token(
-            Key::from(recipient),
-            U256::from(1000),
-            Sender(fixture.ali),
-        );
+        // Fund a reward period of 100 with 1000 rewards tokens
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
 
         let contract_balance_reward_token = fixture.reward_token_balance_of(Key::from(recipient)).unwrap();
         assert_eq!(contract_balance_reward_token, U256::from(1000));
@@ -82,6 +88,2120 @@ mod tests {
         let expected_rewards_balance: U256 = U256::from(blocks) * fixture.reward_rate();
         assert_eq!(rewards_balance, expected_rewards_balance);
     }
+
+    #[test]
+    fn should_start_reward_period() {
+        let mut fixture = TestFixture::install_contract();
+
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+
+        assert_eq!(fixture.reward_rate(), U256::from(10));
+        assert_eq!(fixture.period_finish(), U256::from(100));
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(spender)),
+            Some(U256::from(1000))
+        );
+    }
+
+    #[test]
+    fn should_stop_rewards_at_period_finish() {
+        let mut fixture = TestFixture::install_contract();
+
+        let owner = fixture.bob;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        // Period is over after 100, the rest of the time earns nothing
+        fixture.add_time(150);
+        fixture.withdraw(stake_amount, sender);
+
+        let rewards_balance: U256 = fixture.reward_token_balance_of(Key::from(owner)).unwrap();
+        assert_eq!(rewards_balance, U256::from(1000));
+    }
+
+    #[test]
+    fn should_roll_leftover_into_new_period() {
+        let mut fixture = TestFixture::install_contract();
+
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(500), U256::from(100), Sender(fixture.ali));
+        assert_eq!(fixture.reward_rate(), U256::from(5));
+
+        // Half of the first period (250) is not distributed yet
+        fixture.add_time(50);
+        fixture.notify_reward_amount(U256::from(500), U256::from(100), Sender(fixture.ali));
+
+        assert_eq!(fixture.reward_rate(), U256::from(7));
+        assert_eq!(fixture.period_finish(), U256::from(150));
+    }
+
+    #[test]
+    fn should_transfer_ownership() {
+        let mut fixture = TestFixture::install_contract();
+
+        fixture.transfer_ownership(Address::from(fixture.bob), Sender(fixture.ali));
+
+        // Ownership is only transferred once accepted
+        assert_eq!(fixture.owner(), Key::from(fixture.ali));
+        assert_eq!(fixture.pending_owner(), Some(Key::from(fixture.bob)));
+
+        fixture.accept_ownership(Sender(fixture.bob));
+
+        assert_eq!(fixture.owner(), Key::from(fixture.bob));
+        assert_eq!(fixture.pending_owner(), None);
+    }
+
+    #[should_panic(expected = "ApiError::User(1) [65537]")]
+    #[test]
+    fn should_not_accept_ownership_if_not_pending_owner() {
+        let mut fixture = TestFixture::install_contract();
+
+        fixture.transfer_ownership(Address::from(fixture.bob), Sender(fixture.ali));
+        fixture.accept_ownership(Sender(fixture.joe));
+    }
+
+    #[should_panic(expected = "ApiError::User(1) [65537]")]
+    #[test]
+    fn should_not_set_reward_rate_if_not_owner() {
+        let mut fixture = TestFixture::install_contract();
+
+        fixture.set_reward_rate(U256::from(100), Sender(fixture.bob));
+    }
+
+    #[should_panic(expected = "ApiError::User(1) [65537]")]
+    #[test]
+    fn should_not_notify_reward_amount_if_not_owner() {
+        let mut fixture = TestFixture::install_contract();
+
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+
+        fixture.transfer_reward_token(Key::from(fixture.bob), U256::from(100), Sender(fixture.ali));
+        fixture.approve_reward_token(spender, U256::from(100), Sender(fixture.bob));
+        fixture.notify_reward_amount(U256::from(100), U256::from(10), Sender(fixture.bob));
+    }
+
+    #[test]
+    fn should_keep_accrued_rewards_on_reward_rate_change() {
+        let mut fixture = TestFixture::install_contract();
+
+        let owner = fixture.bob;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        // 10 * 10 accrued under the first rate
+        fixture.add_time(10);
+        fixture.set_reward_rate(U256::from(5), Sender(fixture.ali));
+        assert_eq!(fixture.reward_rate(), U256::from(5));
+
+        // 10 * 5 accrued under the new rate
+        fixture.add_time(10);
+        fixture.withdraw(stake_amount, sender);
+
+        let rewards_balance: U256 = fixture.reward_token_balance_of(Key::from(owner)).unwrap();
+        assert_eq!(rewards_balance, U256::from(150));
+    }
+
+    #[should_panic(expected = "ApiError::User(2) [65538]")]
+    #[test]
+    fn should_not_set_stake_token_while_staked() {
+        let mut fixture = TestFixture::install_contract();
+
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        let reward_token = Key::from(fixture.reward_contract_hash);
+        fixture.set_stake_token(reward_token, Sender(fixture.ali));
+    }
+
+    #[should_panic(expected = "ApiError::User(3) [65539]")]
+    #[test]
+    fn should_not_set_reward_token_during_reward_period() {
+        let mut fixture = TestFixture::install_contract();
+
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+
+        let partner_token = Key::from(fixture.partner_contract_hash);
+        fixture.set_reward_token(partner_token, Sender(fixture.ali));
+    }
+
+    #[should_panic(expected = "ApiError::User(4) [65540]")]
+    #[test]
+    fn should_not_stake_while_paused() {
+        let mut fixture = TestFixture::install_contract();
+
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.pause(Sender(fixture.ali));
+        assert!(fixture.paused());
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+    }
+
+    #[should_panic(expected = "ApiError::User(1) [65537]")]
+    #[test]
+    fn should_not_pause_if_not_owner() {
+        let mut fixture = TestFixture::install_contract();
+
+        fixture.pause(Sender(fixture.bob));
+    }
+
+    #[test]
+    fn should_withdraw_while_paused() {
+        let mut fixture = TestFixture::install_contract();
+
+        let owner = fixture.bob;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+
+        let owner_balance_before = fixture.stake_token_balance_of(Key::from(owner)).unwrap();
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        fixture.add_time(10);
+        fixture.pause(Sender(fixture.ali));
+
+        // Principal is returned, rewards are kept for later
+        fixture.withdraw(stake_amount, sender);
+        assert_eq!(
+            fixture.stake_token_balance_of(Key::from(owner)),
+            Some(owner_balance_before)
+        );
+        assert_eq!(fixture.reward_token_balance_of(Key::from(owner)), None);
+
+        fixture.unpause(Sender(fixture.ali));
+        fixture.get_reward(sender);
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(owner)),
+            Some(U256::from(100))
+        );
+    }
+
+    #[should_panic(expected = "ApiError::User(4) [65540]")]
+    #[test]
+    fn should_not_get_reward_while_paused() {
+        let mut fixture = TestFixture::install_contract();
+
+        fixture.pause(Sender(fixture.ali));
+        fixture.get_reward(Sender(fixture.bob));
+    }
+
+    #[test]
+    fn should_emergency_withdraw_forfeiting_rewards() {
+        let mut fixture = TestFixture::install_contract();
+
+        let owner = fixture.bob;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+
+        let owner_balance_before = fixture.stake_token_balance_of(Key::from(owner)).unwrap();
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        fixture.add_time(10);
+        fixture.emergency_withdraw(sender);
+
+        assert_eq!(
+            fixture.stake_token_balance_of(Key::from(owner)),
+            Some(owner_balance_before)
+        );
+        assert_eq!(fixture.total_supply(), U256::from(0));
+
+        // Rewards accrued before the emergency withdrawal are gone
+        fixture.get_reward(sender);
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(owner)).unwrap_or_default(),
+            U256::from(0)
+        );
+    }
+
+    #[test]
+    fn should_reward_stake_larger_than_distributed_amount() {
+        let mut fixture = TestFixture::install_contract();
+
+        let owner = fixture.bob;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(1000);
+
+        // 1 token per time unit shared by 1000 staked tokens
+        fixture.approve_reward_token(spender, U256::from(100), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(100), U256::from(100), Sender(fixture.ali));
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        fixture.add_time(10);
+        fixture.withdraw(stake_amount, sender);
+
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(owner)),
+            Some(U256::from(10))
+        );
+    }
+
+    #[test]
+    fn should_carry_reward_per_token_dust() {
+        let mut fixture = TestFixture::install_contract();
+
+        let owner = fixture.bob;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(3);
+
+        fixture.approve_reward_token(spender, U256::from(100), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(100), U256::from(100), Sender(fixture.ali));
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        // Joe has nothing staked, his calls only checkpoint 'reward_per_token_stored'
+        fixture.add_time(1);
+        fixture.get_reward(Sender(fixture.joe));
+        assert_eq!(fixture.reward_per_token_dust(), U256::from(1));
+
+        fixture.add_time(1);
+        fixture.get_reward(Sender(fixture.joe));
+        assert_eq!(fixture.reward_per_token_dust(), U256::from(2));
+
+        // Dust adds up to a whole reward, nothing is lost
+        fixture.add_time(1);
+        fixture.withdraw(stake_amount, sender);
+        assert_eq!(fixture.reward_per_token_dust(), U256::from(0));
+
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(owner)),
+            Some(U256::from(3))
+        );
+    }
+
+    #[test]
+    fn should_call_views_without_changing_state() {
+        let mut fixture = TestFixture::install_contract();
+
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        fixture.add_time(10);
+        let reward_per_token_stored = fixture.reward_per_token_stored();
+        let last_update_time = fixture.last_update_time();
+
+        fixture.call_views(Address::from(fixture.bob), Sender(fixture.joe));
+
+        assert_eq!(fixture.reward_per_token_stored(), reward_per_token_stored);
+        assert_eq!(fixture.last_update_time(), last_update_time);
+    }
+
+    #[test]
+    fn should_emit_events() {
+        let mut fixture = TestFixture::install_contract();
+
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(5);
+
+        assert_eq!(fixture.event_count(), 0);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        fixture.add_time(10);
+        fixture.withdraw(stake_amount, sender);
+
+        assert_eq!(fixture.event_count(), 5);
+
+        let event_types: Vec<String> = (0..5)
+            .map(|index| fixture.event(index).unwrap()["event_type"].clone())
+            .collect();
+        assert_eq!(
+            event_types,
+            vec!["RewardAdded", "RewardRateUpdated", "Staked", "Withdrawn", "RewardPaid"]
+        );
+
+        let bob = Key::from(fixture.bob).to_formatted_string();
+
+        let staked = fixture.event(2).unwrap();
+        assert_eq!(staked["address"], bob);
+        assert_eq!(staked["amount"], "5");
+        assert_eq!(staked["blocktime"], "0");
+
+        let reward_paid = fixture.event(4).unwrap();
+        assert_eq!(reward_paid["address"], bob);
+        assert_eq!(reward_paid["amount"], "100");
+        assert_eq!(reward_paid["blocktime"], "10");
+        assert_eq!(
+            reward_paid["reward_token"],
+            Key::from(fixture.reward_contract_hash).to_formatted_string()
+        );
+        assert!(!staked.contains_key("reward_token"));
+
+        assert_eq!(fixture.event(5), None);
+    }
+
+    #[should_panic(expected = "ApiError::User(5) [65541]")]
+    #[test]
+    fn should_not_stake_zero_amount() {
+        let mut fixture = TestFixture::install_contract();
+
+        fixture.stake(U256::from(0), Sender(fixture.bob));
+    }
+
+    #[should_panic(expected = "ApiError::User(6) [65542]")]
+    #[test]
+    fn should_not_withdraw_more_than_staked() {
+        let mut fixture = TestFixture::install_contract();
+
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        fixture.withdraw(stake_amount + U256::one(), sender);
+    }
+
+    #[should_panic(expected = "ApiError::User(7) [65543]")]
+    #[test]
+    fn should_not_stake_more_than_approved() {
+        let mut fixture = TestFixture::install_contract();
+
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount + U256::one(), sender);
+    }
+
+    #[test]
+    fn should_keep_unpaid_rewards_owed() {
+        let mut fixture = TestFixture::install_contract();
+
+        let owner = fixture.bob;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        // Rewards owed to bob would now be paid in Partner Token, the reserve of it is empty
+        fixture.add_time(100);
+        let partner_token = Key::from(fixture.partner_contract_hash);
+        fixture.set_reward_token(partner_token, Sender(fixture.ali));
+
+        fixture.get_reward(sender);
+
+        assert_eq!(fixture.partner_token_balance_of(Key::from(owner)), None);
+        assert_eq!(fixture.reward_reserve(), U256::zero());
+        assert_eq!(fixture.outstanding_rewards(), U256::from(1000));
+
+        // Topped up reserve pays a part of the rewards, the rest stays owed
+        fixture.approve_partner_token(spender, U256::from(400), Sender(fixture.ali));
+        fixture.fund_rewards(partner_token, U256::from(400), Sender(fixture.ali));
+        fixture.get_reward(sender);
+
+        assert_eq!(
+            fixture.partner_token_balance_of(Key::from(owner)),
+            Some(U256::from(400))
+        );
+        assert_eq!(fixture.reward_reserve(), U256::zero());
+        assert_eq!(fixture.outstanding_rewards(), U256::from(600));
+    }
+
+    #[test]
+    fn should_recover_previous_reward_token() {
+        let mut fixture = TestFixture::install_contract();
+
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let reward_token = Key::from(fixture.reward_contract_hash);
+        let partner_token = Key::from(fixture.partner_contract_hash);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+
+        // Reserve of the previous Reward Token is not tracked anymore
+        fixture.add_time(100);
+        fixture.set_reward_token(partner_token, Sender(fixture.ali));
+        fixture.recover_tokens(reward_token, U256::from(1000), Sender(fixture.ali));
+
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(fixture.ali)),
+            Some(U256::from(1000))
+        );
+    }
+
+    #[should_panic(expected = "ApiError::User(47) [65583]")]
+    #[test]
+    fn should_not_recover_reward_token() {
+        let mut fixture = TestFixture::install_contract();
+
+        let reward_token = Key::from(fixture.reward_contract_hash);
+        fixture.recover_tokens(reward_token, U256::from(1), Sender(fixture.ali));
+    }
+
+    #[should_panic(expected = "ApiError::User(47) [65583]")]
+    #[test]
+    fn should_not_recover_stake_token() {
+        let mut fixture = TestFixture::install_contract();
+
+        let stake_token = Key::from(fixture.stake_contract_hash);
+        fixture.recover_tokens(stake_token, U256::from(1), Sender(fixture.ali));
+    }
+
+    #[should_panic(expected = "ApiError::User(10) [65546]")]
+    #[test]
+    fn should_not_notify_reward_amount_with_zero_duration() {
+        let mut fixture = TestFixture::install_contract();
+
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(0), Sender(fixture.ali));
+    }
+
+    #[test]
+    fn should_boost_locked_stake() {
+        let mut fixture = TestFixture::install_contract();
+
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+
+        // Locking for 50 doubles the weight of the stake
+        fixture.set_lock_tiers(vec![(U256::from(50), U256::from(20_000))], Sender(fixture.ali));
+
+        fixture.approve_stake_token(spender, U256::from(10), sender);
+        fixture.stake(U256::from(5), sender);
+        fixture.stake_locked(U256::from(5), U256::from(50), sender);
+
+        assert_eq!(fixture.total_supply(), U256::from(10));
+        assert_eq!(fixture.effective_supply(), U256::from(15));
+
+        // Once expired anyone can unlock the position, the boost is removed
+        fixture.add_time(50);
+        fixture.unlock_position(Address::from(fixture.bob), 0, Sender(fixture.joe));
+        assert_eq!(fixture.effective_supply(), U256::from(10));
+
+        fixture.withdraw(U256::from(10), sender);
+        assert_eq!(fixture.total_supply(), U256::from(0));
+        assert_eq!(fixture.effective_supply(), U256::from(0));
+    }
+
+    #[should_panic(expected = "ApiError::User(13) [65549]")]
+    #[test]
+    fn should_not_withdraw_locked_stake() {
+        let mut fixture = TestFixture::install_contract();
+
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+
+        fixture.set_lock_tiers(vec![(U256::from(50), U256::from(20_000))], Sender(fixture.ali));
+
+        fixture.approve_stake_token(spender, U256::from(5), sender);
+        fixture.stake_locked(U256::from(5), U256::from(50), sender);
+
+        fixture.add_time(10);
+        fixture.withdraw(U256::from(5), sender);
+    }
+
+    #[should_panic(expected = "ApiError::User(14) [65550]")]
+    #[test]
+    fn should_not_stake_locked_without_lock_tier() {
+        let mut fixture = TestFixture::install_contract();
+
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+
+        fixture.approve_stake_token(spender, U256::from(5), sender);
+        fixture.stake_locked(U256::from(5), U256::from(50), sender);
+    }
+
+    #[test]
+    fn should_pay_every_reward_token() {
+        let mut fixture = TestFixture::install_contract();
+
+        let owner = fixture.bob;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let reward_token = Key::from(fixture.reward_contract_hash);
+        let partner_token = Key::from(fixture.partner_contract_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.add_reward_token(partner_token, Sender(fixture.ali));
+        assert_eq!(fixture.reward_tokens(), vec![reward_token, partner_token]);
+
+        // Each reward token has its own rate, 10 and 5 per time unit
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+        fixture.approve_partner_token(spender, U256::from(500), Sender(fixture.ali));
+        fixture.notify_reward_amount_for(partner_token, U256::from(500), U256::from(100), Sender(fixture.ali));
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        fixture.add_time(10);
+        fixture.get_reward(sender);
+
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(owner)),
+            Some(U256::from(100))
+        );
+        assert_eq!(
+            fixture.partner_token_balance_of(Key::from(owner)),
+            Some(U256::from(50))
+        );
+    }
+
+    #[test]
+    fn should_get_reward_for_one_reward_token() {
+        let mut fixture = TestFixture::install_contract();
+
+        let owner = fixture.bob;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let partner_token = Key::from(fixture.partner_contract_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.add_reward_token(partner_token, Sender(fixture.ali));
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+        fixture.approve_partner_token(spender, U256::from(500), Sender(fixture.ali));
+        fixture.notify_reward_amount_for(partner_token, U256::from(500), U256::from(100), Sender(fixture.ali));
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        fixture.add_time(10);
+        fixture.get_reward_for(partner_token, sender);
+
+        assert_eq!(
+            fixture.partner_token_balance_of(Key::from(owner)),
+            Some(U256::from(50))
+        );
+        assert_eq!(fixture.reward_token_balance_of(Key::from(owner)), None);
+
+        // Rewards of the other token stay credited
+        fixture.get_reward(sender);
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(owner)),
+            Some(U256::from(100))
+        );
+        assert_eq!(
+            fixture.partner_token_balance_of(Key::from(owner)),
+            Some(U256::from(50))
+        );
+    }
+
+    #[should_panic(expected = "ApiError::User(17) [65553]")]
+    #[test]
+    fn should_not_get_reward_for_unknown_reward_token() {
+        let mut fixture = TestFixture::install_contract();
+
+        let partner_token = Key::from(fixture.partner_contract_hash);
+        fixture.get_reward_for(partner_token, Sender(fixture.bob));
+    }
+
+    #[should_panic(expected = "ApiError::User(49) [65585]")]
+    #[test]
+    fn should_not_add_stake_token_as_reward_token() {
+        let mut fixture = TestFixture::install_contract();
+
+        let stake_token = Key::from(fixture.stake_contract_hash);
+        fixture.add_reward_token(stake_token, Sender(fixture.ali));
+    }
+
+    #[should_panic(expected = "ApiError::User(49) [65585]")]
+    #[test]
+    fn should_not_set_reward_token_to_stake_token() {
+        let mut fixture = TestFixture::install_contract();
+
+        let stake_token = Key::from(fixture.stake_contract_hash);
+        fixture.set_reward_token(stake_token, Sender(fixture.ali));
+    }
+
+    #[should_panic(expected = "ApiError::User(18) [65554]")]
+    #[test]
+    fn should_not_add_reward_token_twice() {
+        let mut fixture = TestFixture::install_contract();
+
+        let reward_token = Key::from(fixture.reward_contract_hash);
+        fixture.add_reward_token(reward_token, Sender(fixture.ali));
+    }
+
+    #[test]
+    fn should_split_chef_rewards_by_alloc_points() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.install_chef();
+
+        let chef: Address = Address::from(fixture.chef_contract_package_hash.unwrap());
+        let stake_token = Key::from(fixture.stake_contract_hash);
+        let partner_token = Key::from(fixture.partner_contract_hash);
+
+        // Whole Reward Token supply of ali funds the chef at 10 per second
+        fixture.approve_reward_token(chef, U256::from(1000), Sender(fixture.ali));
+        fixture.chef_notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+
+        // Pool 0 gets 1/4 of the rate, pool 1 gets 3/4
+        fixture.add_pool(stake_token, U256::from(1), Sender(fixture.ali));
+        fixture.add_pool(partner_token, U256::from(3), Sender(fixture.ali));
+
+        fixture.approve_stake_token(chef, U256::from(5), Sender(fixture.bob));
+        fixture.chef_stake(0, U256::from(5), Sender(fixture.bob));
+        fixture.approve_partner_token(chef, U256::from(5), Sender(fixture.ali));
+        fixture.chef_stake(1, U256::from(5), Sender(fixture.ali));
+
+        fixture.add_time(10);
+        fixture.chef_get_reward(0, Sender(fixture.bob));
+        fixture.chef_withdraw(1, U256::from(5), Sender(fixture.ali));
+
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(fixture.bob)),
+            Some(U256::from(25))
+        );
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(fixture.ali)),
+            Some(U256::from(75))
+        );
+
+        // Pool 1 is stopped, pool 0 gets the whole rate
+        fixture.set_pool(1, U256::from(0), Sender(fixture.ali));
+        fixture.add_time(10);
+        fixture.chef_withdraw(0, U256::from(5), Sender(fixture.bob));
+
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(fixture.bob)),
+            Some(U256::from(125))
+        );
+    }
+
+    #[test]
+    fn should_stop_chef_rewards_at_period_finish() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.install_chef();
+
+        let chef: Address = Address::from(fixture.chef_contract_package_hash.unwrap());
+        let stake_token = Key::from(fixture.stake_contract_hash);
+
+        fixture.add_pool(stake_token, U256::from(1), Sender(fixture.ali));
+
+        fixture.approve_stake_token(chef, U256::from(5), Sender(fixture.bob));
+        fixture.chef_stake(0, U256::from(5), Sender(fixture.bob));
+
+        // 100 tokens over 10 seconds
+        fixture.approve_reward_token(chef, U256::from(100), Sender(fixture.ali));
+        fixture.chef_notify_reward_amount(U256::from(100), U256::from(10), Sender(fixture.ali));
+
+        // Nothing accrues after the period is over
+        fixture.add_time(20);
+        fixture.chef_withdraw(0, U256::from(5), Sender(fixture.bob));
+
+        assert_eq!(
+            fixture.stake_token_balance_of(Key::from(fixture.bob)),
+            Some(U256::from(1000))
+        );
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(fixture.bob)),
+            Some(U256::from(100))
+        );
+    }
+
+    #[should_panic(expected = "ApiError::User(9) [65545]")]
+    #[test]
+    fn should_not_set_chef_reward_rate_above_reserve() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.install_chef();
+
+        let chef: Address = Address::from(fixture.chef_contract_package_hash.unwrap());
+
+        fixture.approve_reward_token(chef, U256::from(100), Sender(fixture.ali));
+        fixture.chef_notify_reward_amount(U256::from(100), U256::from(10), Sender(fixture.ali));
+
+        // 20 per second for the remaining 10 seconds needs 200 tokens
+        fixture.chef_set_reward_rate(U256::from(20), Sender(fixture.ali));
+    }
+
+    #[test]
+    fn should_emergency_withdraw_from_chef_pool() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.install_chef();
+
+        let chef: Address = Address::from(fixture.chef_contract_package_hash.unwrap());
+        let stake_token = Key::from(fixture.stake_contract_hash);
+
+        fixture.add_pool(stake_token, U256::from(1), Sender(fixture.ali));
+
+        fixture.approve_reward_token(chef, U256::from(100), Sender(fixture.ali));
+        fixture.chef_notify_reward_amount(U256::from(100), U256::from(10), Sender(fixture.ali));
+
+        fixture.approve_stake_token(chef, U256::from(5), Sender(fixture.bob));
+        fixture.chef_stake(0, U256::from(5), Sender(fixture.bob));
+
+        // Rewards are forfeited, the stake comes back
+        fixture.add_time(10);
+        fixture.chef_emergency_withdraw(0, Sender(fixture.bob));
+
+        assert_eq!(
+            fixture.stake_token_balance_of(Key::from(fixture.bob)),
+            Some(U256::from(1000))
+        );
+        assert_eq!(fixture.reward_token_balance_of(Key::from(fixture.bob)), None);
+    }
+
+    #[should_panic(expected = "ApiError::User(1000) [66536]")]
+    #[test]
+    fn should_not_stake_in_unknown_pool() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.install_chef();
+
+        fixture.chef_stake(0, U256::from(5), Sender(fixture.bob));
+    }
+
+    #[should_panic(expected = "ApiError::User(1001) [66537]")]
+    #[test]
+    fn should_not_add_pool_twice() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.install_chef();
+
+        let stake_token = Key::from(fixture.stake_contract_hash);
+        fixture.add_pool(stake_token, U256::from(1), Sender(fixture.ali));
+        fixture.add_pool(stake_token, U256::from(1), Sender(fixture.ali));
+    }
+
+    #[test]
+    fn should_compound_rewards_of_own_token() {
+        let mut fixture = TestFixture::install_contract();
+
+        let owner = fixture.ali;
+        let sender = Sender(fixture.ali);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let reward_token = Key::from(fixture.reward_contract_hash);
+
+        assert!(!fixture.compounding_enabled());
+
+        // The pool stakes its own Reward Token
+        fixture.set_stake_token(reward_token, sender);
+        assert!(fixture.compounding_enabled());
+
+        fixture.approve_reward_token(spender, U256::from(600), sender);
+        fixture.notify_reward_amount(U256::from(500), U256::from(100), sender);
+        fixture.stake(U256::from(100), sender);
+
+        fixture.add_time(10);
+        fixture.compound(sender);
+        assert_eq!(fixture.total_supply(), U256::from(150));
+
+        // Restaked rewards are withdrawn as principal, nothing is left to pay
+        fixture.withdraw(U256::from(150), sender);
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(owner)),
+            Some(U256::from(550))
+        );
+    }
+
+    #[should_panic(expected = "ApiError::User(22) [65558]")]
+    #[test]
+    fn should_not_compound_other_token() {
+        let mut fixture = TestFixture::install_contract();
+
+        fixture.compound(Sender(fixture.bob));
+    }
+
+    #[should_panic(expected = "ApiError::User(22) [65558]")]
+    #[test]
+    fn should_not_compound_rewards_that_vest() {
+        let mut fixture = TestFixture::install_contract_with(runtime_args! {
+            VESTING_PERIOD_KEY_NAME => U256::from(100)
+        });
+
+        let sender = Sender(fixture.ali);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let reward_token = Key::from(fixture.reward_contract_hash);
+
+        // Restaking would skip the escrow
+        fixture.set_stake_token(reward_token, sender);
+        assert!(!fixture.compounding_enabled());
+
+        fixture.approve_reward_token(spender, U256::from(600), sender);
+        fixture.notify_reward_amount(U256::from(500), U256::from(100), sender);
+        fixture.stake(U256::from(100), sender);
+
+        fixture.add_time(10);
+        fixture.compound(sender);
+    }
+
+    #[test]
+    fn should_stake_for_beneficiary() {
+        let mut fixture = TestFixture::install_contract();
+
+        let payer = fixture.bob;
+        let beneficiary = fixture.joe;
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+
+        // Stake Token is pulled from the payer
+        fixture.approve_stake_token(spender, stake_amount, Sender(payer));
+        fixture.stake_for(Address::from(beneficiary), stake_amount, Sender(payer));
+
+        assert_eq!(
+            fixture.stake_token_balance_of(Key::from(payer)),
+            Some(U256::from(995))
+        );
+        assert_eq!(fixture.total_supply(), stake_amount);
+
+        // The beneficiary owns the stake and its rewards
+        fixture.add_time(10);
+        fixture.withdraw(stake_amount, Sender(beneficiary));
+
+        assert_eq!(
+            fixture.stake_token_balance_of(Key::from(beneficiary)),
+            Some(stake_amount)
+        );
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(beneficiary)),
+            Some(U256::from(100))
+        );
+        assert_eq!(fixture.reward_token_balance_of(Key::from(payer)), None);
+    }
+
+    #[test]
+    fn should_withdraw_to_recipient() {
+        let mut fixture = TestFixture::install_contract();
+
+        let owner = fixture.bob;
+        let recipient = fixture.joe;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        fixture.add_time(10);
+        fixture.withdraw_to(Address::from(recipient), stake_amount, sender);
+
+        // Principal and rewards go to the recipient
+        assert_eq!(
+            fixture.stake_token_balance_of(Key::from(recipient)),
+            Some(stake_amount)
+        );
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(recipient)),
+            Some(U256::from(100))
+        );
+        assert_eq!(
+            fixture.stake_token_balance_of(Key::from(owner)),
+            Some(U256::from(995))
+        );
+        assert_eq!(fixture.total_supply(), U256::zero());
+    }
+
+    #[test]
+    fn should_get_reward_to_recipient() {
+        let mut fixture = TestFixture::install_contract();
+
+        let owner = fixture.bob;
+        let recipient = fixture.joe;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        fixture.add_time(10);
+        fixture.get_reward_to(Address::from(recipient), sender);
+
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(recipient)),
+            Some(U256::from(100))
+        );
+        assert_eq!(fixture.reward_token_balance_of(Key::from(owner)), None);
+        assert_eq!(fixture.total_supply(), stake_amount);
+    }
+
+    #[test]
+    fn should_upgrade_contract_keeping_state() {
+        let mut fixture = TestFixture::install_contract();
+
+        let owner = fixture.bob;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        let previous_contract_hash = fixture.staking_contract_hash;
+        fixture.add_time(10);
+        fixture.upgrade_contract();
+
+        assert_ne!(fixture.staking_contract_hash, previous_contract_hash);
+        assert_eq!(fixture.contract_version(), 1);
+        assert_eq!(fixture.total_supply(), stake_amount);
+
+        // Balances and rewards accrued before the upgrade are kept
+        fixture.withdraw(stake_amount, sender);
+
+        assert_eq!(
+            fixture.stake_token_balance_of(Key::from(owner)),
+            Some(U256::from(1000))
+        );
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(owner)),
+            Some(U256::from(100))
+        );
+    }
+
+    #[should_panic(expected = "ApiError::User(1) [65537]")]
+    #[test]
+    fn should_not_migrate_without_ownership() {
+        let mut fixture = TestFixture::install_contract();
+
+        fixture.migrate(Sender(fixture.bob));
+    }
+
+    #[test]
+    fn should_store_token_decimals() {
+        let fixture = TestFixture::install_contract();
+
+        assert_eq!(fixture.stake_token_decimals(), 9);
+        assert_eq!(fixture.reward_token_decimals(), 9);
+    }
+
+    #[should_panic(expected = "ApiError::User(24) [65560]")]
+    #[test]
+    fn should_not_install_with_account_as_stake_token() {
+        let mut fixture = TestFixture::install_contract();
+
+        let account = Key::from(fixture.joe);
+        fixture.install_another_contract_with(runtime_args! {
+            STAKE_TOKEN_HASH_KEY_NAME => account
+        });
+    }
+
+    #[should_panic(expected = "NoSuchMethod")]
+    #[test]
+    fn should_not_install_with_contract_other_than_erc20_as_reward_token() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.install_governance(U256::from(100), U256::from(0), U256::from(4_000), U256::from(5_000));
+
+        // The governance contract has no ERC20 entry points
+        let governance = Key::from(fixture.governance_contract_hash.unwrap());
+        fixture.install_another_contract_with(runtime_args! {
+            REWARD_TOKEN_HASH_KEY_NAME => governance
+        });
+    }
+
+    #[should_panic(expected = "ApiError::User(24) [65560]")]
+    #[test]
+    fn should_not_set_stake_token_to_account() {
+        let mut fixture = TestFixture::install_contract();
+
+        let account = Key::from(fixture.joe);
+        fixture.set_stake_token(account, Sender(fixture.ali));
+    }
+
+    #[test]
+    fn should_fund_rewards_for_higher_reward_rate() {
+        let mut fixture = TestFixture::install_contract();
+
+        let owner = fixture.bob;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let reward_token = Key::from(fixture.reward_contract_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(500), U256::from(100), Sender(fixture.ali));
+        fixture.fund_rewards(reward_token, U256::from(500), Sender(fixture.ali));
+
+        // 10 * 100 is covered by the reserve
+        fixture.set_reward_rate(U256::from(10), Sender(fixture.ali));
+        assert_eq!(fixture.reward_reserve(), U256::from(1000));
+        assert_eq!(fixture.outstanding_rewards(), U256::from(1000));
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        fixture.add_time(10);
+        fixture.get_reward(sender);
+
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(owner)),
+            Some(U256::from(100))
+        );
+        assert_eq!(fixture.reward_reserve(), U256::from(900));
+        assert_eq!(fixture.outstanding_rewards(), U256::from(900));
+    }
+
+    #[should_panic(expected = "ApiError::User(9) [65545]")]
+    #[test]
+    fn should_not_set_reward_rate_above_reward_reserve() {
+        let mut fixture = TestFixture::install_contract();
+
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+
+        fixture.set_reward_rate(U256::from(11), Sender(fixture.ali));
+    }
+
+    #[test]
+    fn should_unbond_withdrawn_stake() {
+        let mut fixture = TestFixture::install_contract_with(runtime_args! {
+            UNBONDING_PERIOD_KEY_NAME => U256::from(50)
+        });
+
+        let owner = fixture.bob;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        fixture.add_time(10);
+        fixture.request_withdraw(stake_amount, sender);
+
+        assert_eq!(fixture.total_supply(), U256::zero());
+        assert_eq!(
+            fixture.unbonding_entries(Key::from(owner)),
+            vec![(stake_amount, U256::from(60))]
+        );
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(owner)),
+            Some(U256::from(100))
+        );
+
+        // Unbonding stake earns nothing
+        fixture.add_time(50);
+        fixture.get_reward(sender);
+        fixture.claim_unbonded(sender);
+
+        assert_eq!(
+            fixture.stake_token_balance_of(Key::from(owner)),
+            Some(U256::from(1000))
+        );
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(owner)),
+            Some(U256::from(100))
+        );
+        assert!(fixture.unbonding_entries(Key::from(owner)).is_empty());
+    }
+
+    #[should_panic(expected = "ApiError::User(26) [65562]")]
+    #[test]
+    fn should_not_claim_unbonded_before_unlock_time() {
+        let mut fixture = TestFixture::install_contract_with(runtime_args! {
+            UNBONDING_PERIOD_KEY_NAME => U256::from(50)
+        });
+
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+        fixture.request_withdraw(stake_amount, sender);
+
+        fixture.add_time(49);
+        fixture.claim_unbonded(sender);
+    }
+
+    #[should_panic(expected = "ApiError::User(2) [65538]")]
+    #[test]
+    fn should_not_set_stake_token_while_unbonding() {
+        let mut fixture = TestFixture::install_contract_with(runtime_args! {
+            UNBONDING_PERIOD_KEY_NAME => U256::from(50)
+        });
+
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+        fixture.request_withdraw(stake_amount, sender);
+
+        // Pool is empty but the queued stake is still owed in the Stake Token
+        let reward_token = Key::from(fixture.reward_contract_hash);
+        fixture.set_stake_token(reward_token, Sender(fixture.ali));
+    }
+
+    #[should_panic(expected = "ApiError::User(25) [65561]")]
+    #[test]
+    fn should_not_withdraw_during_unbonding_period() {
+        let mut fixture = TestFixture::install_contract_with(runtime_args! {
+            UNBONDING_PERIOD_KEY_NAME => U256::from(50)
+        });
+
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+        fixture.withdraw(stake_amount, sender);
+    }
+
+    #[test]
+    fn should_redistribute_early_exit_penalty() {
+        let mut fixture = TestFixture::install_single_token_contract_with(runtime_args! {
+            PENALTY_PERIOD_KEY_NAME => U256::from(50),
+            PENALTY_RATE_KEY_NAME => U256::from(1_000)
+        });
+
+        let ali = fixture.ali;
+        let bob = fixture.bob;
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+
+        assert_eq!(fixture.treasury(), None);
+
+        // The fee is paid in Stake Token, shared through its reward token slot
+        fixture.transfer_reward_token(Key::from(bob), U256::from(100), Sender(ali));
+
+        fixture.approve_reward_token(spender, U256::from(100), Sender(ali));
+        fixture.stake(U256::from(100), Sender(ali));
+        fixture.approve_reward_token(spender, U256::from(100), Sender(bob));
+        fixture.stake(U256::from(100), Sender(bob));
+
+        // 10% of the stake of bob goes to ali
+        fixture.add_time(10);
+        fixture.withdraw(U256::from(100), Sender(bob));
+        fixture.get_reward(Sender(ali));
+
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(bob)),
+            Some(U256::from(90))
+        );
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(ali)),
+            Some(U256::from(810))
+        );
+        assert_eq!(fixture.total_supply(), U256::from(100));
+    }
+
+    #[test]
+    fn should_send_early_exit_penalty_to_treasury() {
+        let treasury = AccountHash::new([7u8; 32]);
+        let mut fixture = TestFixture::install_contract_with(runtime_args! {
+            PENALTY_PERIOD_KEY_NAME => U256::from(50),
+            PENALTY_RATE_KEY_NAME => U256::from(1_000),
+            TREASURY_KEY_NAME => Some(Address::from(treasury))
+        });
+
+        let owner = fixture.bob;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(100);
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        fixture.add_time(10);
+        fixture.withdraw(stake_amount, sender);
+
+        assert_eq!(
+            fixture.stake_token_balance_of(Key::from(owner)),
+            Some(U256::from(990))
+        );
+        assert_eq!(
+            fixture.stake_token_balance_of(Key::from(treasury)),
+            Some(U256::from(10))
+        );
+
+        // The penalty is reported before the withdrawal of what is left
+        let penalty_charged = fixture.event(1).unwrap();
+        assert_eq!(penalty_charged["event_type"], "PenaltyCharged");
+        assert_eq!(penalty_charged["address"], Key::from(owner).to_formatted_string());
+        assert_eq!(penalty_charged["amount"], "10");
+        assert_eq!(penalty_charged["blocktime"], "10");
+
+        let withdrawn = fixture.event(2).unwrap();
+        assert_eq!(withdrawn["event_type"], "Withdrawn");
+        assert_eq!(withdrawn["amount"], "90");
+    }
+
+    #[test]
+    fn should_emergency_withdraw_raw_principal() {
+        let treasury = AccountHash::new([7u8; 32]);
+        let mut fixture = TestFixture::install_contract_with(runtime_args! {
+            PENALTY_PERIOD_KEY_NAME => U256::from(50),
+            PENALTY_RATE_KEY_NAME => U256::from(1_000),
+            TREASURY_KEY_NAME => Some(Address::from(treasury)),
+            UNBONDING_PERIOD_KEY_NAME => U256::from(50)
+        });
+
+        let owner = fixture.bob;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(100);
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        // Neither the penalty nor the unbonding period apply to the emergency exit
+        fixture.add_time(10);
+        fixture.emergency_withdraw(sender);
+
+        assert_eq!(
+            fixture.stake_token_balance_of(Key::from(owner)),
+            Some(U256::from(1000))
+        );
+        assert_eq!(fixture.stake_token_balance_of(Key::from(treasury)), None);
+        assert!(fixture.unbonding_entries(Key::from(owner)).is_empty());
+    }
+
+    #[test]
+    fn should_not_charge_penalty_after_penalty_period() {
+        let treasury = AccountHash::new([7u8; 32]);
+        let mut fixture = TestFixture::install_contract_with(runtime_args! {
+            PENALTY_PERIOD_KEY_NAME => U256::from(50),
+            PENALTY_RATE_KEY_NAME => U256::from(1_000),
+            TREASURY_KEY_NAME => Some(Address::from(treasury))
+        });
+
+        let owner = fixture.bob;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(100);
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        fixture.add_time(50);
+        fixture.withdraw(stake_amount, sender);
+
+        assert_eq!(
+            fixture.stake_token_balance_of(Key::from(owner)),
+            Some(U256::from(1000))
+        );
+    }
+
+    #[test]
+    fn should_not_restart_penalty_period_on_stake_for() {
+        let treasury = AccountHash::new([7u8; 32]);
+        let mut fixture = TestFixture::install_contract_with(runtime_args! {
+            PENALTY_PERIOD_KEY_NAME => U256::from(50),
+            PENALTY_RATE_KEY_NAME => U256::from(1_000),
+            TREASURY_KEY_NAME => Some(Address::from(treasury))
+        });
+
+        let owner = fixture.bob;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(100);
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        // A small deposit from someone else only moves the period start by its weight
+        fixture.add_time(45);
+        fixture.transfer_stake_token(Key::from(fixture.joe), U256::from(1), sender);
+        fixture.approve_stake_token(spender, U256::from(1), Sender(fixture.joe));
+        fixture.stake_for(Address::from(owner), U256::from(1), Sender(fixture.joe));
+
+        assert_eq!(fixture.weighted_stake_time(Key::from(owner)), U256::zero());
+
+        fixture.add_time(5);
+        fixture.withdraw(U256::from(101), sender);
+
+        assert_eq!(
+            fixture.stake_token_balance_of(Key::from(owner)),
+            Some(U256::from(1000))
+        );
+        assert_eq!(fixture.stake_token_balance_of(Key::from(treasury)), None);
+    }
+
+    #[should_panic(expected = "ApiError::User(27) [65563]")]
+    #[test]
+    fn should_not_install_with_penalty_rate_above_whole_amount() {
+        TestFixture::install_contract_with(runtime_args! {
+            PENALTY_RATE_KEY_NAME => U256::from(10_001)
+        });
+    }
+
+    #[should_panic(expected = "ApiError::User(46) [65582]")]
+    #[test]
+    fn should_not_install_with_penalty_nobody_can_receive() {
+        TestFixture::install_contract_with(runtime_args! {
+            PENALTY_PERIOD_KEY_NAME => U256::from(50),
+            PENALTY_RATE_KEY_NAME => U256::from(1_000)
+        });
+    }
+
+    #[should_panic(expected = "ApiError::User(46) [65582]")]
+    #[test]
+    fn should_not_replace_stake_token_receiving_penalty() {
+        let mut fixture = TestFixture::install_single_token_contract_with(runtime_args! {
+            PENALTY_PERIOD_KEY_NAME => U256::from(50),
+            PENALTY_RATE_KEY_NAME => U256::from(1_000)
+        });
+
+        // The fee could not be redistributed in another Stake Token
+        let stake_token = Key::from(fixture.stake_contract_hash);
+        fixture.set_stake_token(stake_token, Sender(fixture.ali));
+    }
+
+    #[test]
+    fn should_vest_claimed_rewards_linearly() {
+        let mut fixture = TestFixture::install_contract_with(runtime_args! {
+            VESTING_PERIOD_KEY_NAME => U256::from(100)
+        });
+
+        let owner = fixture.bob;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        // Claimed rewards go to the escrow, nothing is transferred yet
+        fixture.add_time(10);
+        fixture.get_reward(sender);
+
+        assert_eq!(
+            fixture.vesting_escrow(Key::from(owner)),
+            vec![(U256::from(100), U256::from(10), U256::from(110))]
+        );
+        assert_eq!(fixture.reward_token_balance_of(Key::from(owner)), None);
+
+        // Half of the vesting period unlocks half of the escrow
+        fixture.add_time(50);
+        fixture.claim_vested(sender);
+
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(owner)),
+            Some(U256::from(50))
+        );
+        assert_eq!(
+            fixture.vesting_escrow(Key::from(owner)),
+            vec![(U256::from(50), U256::from(60), U256::from(110))]
+        );
+        assert_eq!(fixture.vested_rewards(Key::from(owner)), U256::zero());
+
+        fixture.add_time(50);
+        fixture.claim_vested(sender);
+
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(owner)),
+            Some(U256::from(100))
+        );
+    }
+
+    #[should_panic(expected = "ApiError::User(50) [65586]")]
+    #[test]
+    fn should_not_get_reward_to_another_recipient_while_vesting() {
+        let mut fixture = TestFixture::install_contract_with(runtime_args! {
+            VESTING_PERIOD_KEY_NAME => U256::from(100)
+        });
+
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+
+        fixture.approve_stake_token(spender, U256::from(5), sender);
+        fixture.stake(U256::from(5), sender);
+
+        // Rewards would vest in the escrow of bob, not reach joe
+        fixture.add_time(10);
+        fixture.get_reward_to(Address::from(fixture.joe), sender);
+    }
+
+    #[should_panic(expected = "ApiError::User(50) [65586]")]
+    #[test]
+    fn should_not_withdraw_to_another_recipient_while_vesting() {
+        let mut fixture = TestFixture::install_contract_with(runtime_args! {
+            VESTING_PERIOD_KEY_NAME => U256::from(100)
+        });
+
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+
+        fixture.approve_stake_token(spender, U256::from(5), sender);
+        fixture.stake(U256::from(5), sender);
+
+        fixture.add_time(10);
+        fixture.withdraw_to(Address::from(fixture.joe), U256::from(5), sender);
+    }
+
+    #[should_panic(expected = "ApiError::User(48) [65584]")]
+    #[test]
+    fn should_not_set_reward_token_with_escrowed_rewards() {
+        let mut fixture = TestFixture::install_contract_with(runtime_args! {
+            VESTING_PERIOD_KEY_NAME => U256::from(100)
+        });
+
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        // The period is over but its rewards still vest in Reward Token
+        fixture.add_time(100);
+        fixture.get_reward(sender);
+
+        let partner_token = Key::from(fixture.partner_contract_hash);
+        fixture.set_reward_token(partner_token, Sender(fixture.ali));
+    }
+
+    #[test]
+    fn should_keep_vesting_schedule_of_earlier_escrows() {
+        let mut fixture = TestFixture::install_contract_with(runtime_args! {
+            VESTING_PERIOD_KEY_NAME => U256::from(100)
+        });
+
+        let owner = fixture.bob;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        fixture.add_time(10);
+        fixture.get_reward(sender);
+
+        // A second escrow vests on its own, the first one still ends at 110
+        fixture.add_time(50);
+        fixture.get_reward(sender);
+
+        assert_eq!(
+            fixture.vesting_escrow(Key::from(owner)),
+            vec![
+                (U256::from(100), U256::from(10), U256::from(110)),
+                (U256::from(500), U256::from(60), U256::from(160))
+            ]
+        );
+
+        // 100 of the first tranche and 250 of the second one
+        fixture.add_time(50);
+        fixture.claim_vested(sender);
+
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(owner)),
+            Some(U256::from(350))
+        );
+        assert_eq!(
+            fixture.vesting_escrow(Key::from(owner)),
+            vec![(U256::from(250), U256::from(110), U256::from(160))]
+        );
+    }
+
+    #[test]
+    fn should_escrow_rewards_paid_to_another_recipient_for_the_staker() {
+        let mut fixture = TestFixture::install_contract_with(runtime_args! {
+            VESTING_PERIOD_KEY_NAME => U256::from(100)
+        });
+
+        let owner = fixture.bob;
+        let recipient = fixture.joe;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        fixture.add_time(10);
+        fixture.get_reward_to(Address::from(recipient), sender);
+
+        // The escrow of the recipient is never written by another account
+        assert_eq!(fixture.vesting_escrow(Key::from(recipient)), vec![]);
+        assert_eq!(
+            fixture.vesting_escrow(Key::from(owner)),
+            vec![(U256::from(100), U256::from(10), U256::from(110))]
+        );
+    }
+
+    #[should_panic(expected = "ApiError::User(28) [65564]")]
+    #[test]
+    fn should_not_claim_vested_rewards_before_vesting() {
+        let mut fixture = TestFixture::install_contract_with(runtime_args! {
+            VESTING_PERIOD_KEY_NAME => U256::from(100)
+        });
+
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let stake_amount = U256::from(5);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+
+        fixture.approve_stake_token(spender, stake_amount, sender);
+        fixture.stake(stake_amount, sender);
+
+        fixture.add_time(10);
+        fixture.get_reward(sender);
+        fixture.claim_vested(sender);
+    }
+
+    #[test]
+    fn should_boost_rewards_of_boost_token_holders() {
+        let mut fixture = TestFixture::install_contract();
+
+        let ali = fixture.ali;
+        let bob = fixture.bob;
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let boost_token = Key::from(fixture.partner_contract_hash);
+
+        fixture.set_boost_token(boost_token, Sender(ali));
+        fixture.approve_partner_token(spender, U256::from(100), Sender(ali));
+        fixture.lock_boost(U256::from(100), Sender(ali));
+
+        fixture.approve_reward_token(spender, U256::from(700), Sender(ali));
+        fixture.notify_reward_amount(U256::from(700), U256::from(100), Sender(ali));
+
+        // bob stakes the same amount for ali and for himself, only ali holds the Boost Token
+        fixture.approve_stake_token(spender, U256::from(200), Sender(bob));
+        fixture.stake_for(Address::from(ali), U256::from(100), Sender(bob));
+        fixture.stake(U256::from(100), Sender(bob));
+
+        assert_eq!(fixture.working_balance(Key::from(ali)), U256::from(100));
+        assert_eq!(fixture.working_balance(Key::from(bob)), U256::from(40));
+        assert_eq!(fixture.working_supply(), U256::from(140));
+
+        fixture.add_time(100);
+        fixture.get_reward(Sender(ali));
+        fixture.get_reward(Sender(bob));
+
+        // 2.5x the rewards of bob
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(ali)),
+            Some(U256::from(800))
+        );
+        assert_eq!(
+            fixture.reward_token_balance_of(Key::from(bob)),
+            Some(U256::from(200))
+        );
+
+        // Without Boost Token ali falls back to the unboosted share
+        fixture.unlock_boost(U256::from(100), Sender(ali));
+
+        assert_eq!(fixture.working_balance(Key::from(ali)), U256::from(40));
+        assert_eq!(
+            fixture.partner_token_balance_of(Key::from(ali)),
+            Some(U256::from(1000))
+        );
+    }
+
+    #[should_panic(expected = "ApiError::User(29) [65565]")]
+    #[test]
+    fn should_not_lock_boost_without_boost_token() {
+        let mut fixture = TestFixture::install_contract();
+
+        fixture.lock_boost(U256::from(100), Sender(fixture.ali));
+    }
+
+    #[test]
+    fn should_checkpoint_balances() {
+        let mut fixture = TestFixture::install_contract();
+
+        let owner = fixture.bob;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+
+        fixture.approve_stake_token(spender, U256::from(10), sender);
+
+        fixture.add_time(10);
+        fixture.stake(U256::from(5), sender);
+
+        // Changes of the same block time share one checkpoint
+        fixture.add_time(10);
+        fixture.stake(U256::from(5), sender);
+        fixture.withdraw(U256::from(2), sender);
+
+        fixture.add_time(10);
+        fixture.withdraw(U256::from(3), sender);
+
+        assert_eq!(
+            fixture.balance_checkpoint(Key::from(owner), 0),
+            Some((U256::from(10), U256::from(5)))
+        );
+        assert_eq!(
+            fixture.balance_checkpoint(Key::from(owner), 1),
+            Some((U256::from(20), U256::from(8)))
+        );
+        assert_eq!(
+            fixture.balance_checkpoint(Key::from(owner), 2),
+            Some((U256::from(30), U256::from(5)))
+        );
+        assert_eq!(fixture.balance_checkpoint(Key::from(owner), 3), None);
+        assert_eq!(
+            fixture.total_supply_checkpoint(2),
+            Some((U256::from(30), U256::from(5)))
+        );
+
+        fixture.add_time(1);
+        fixture.call_snapshot_views(Address::from(owner), U256::from(30), sender);
+    }
+
+    #[should_panic(expected = "ApiError::User(31) [65567]")]
+    #[test]
+    fn should_not_read_snapshot_of_current_block_time() {
+        let mut fixture = TestFixture::install_contract();
+
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+
+        fixture.approve_stake_token(spender, U256::from(5), sender);
+        fixture.add_time(10);
+        fixture.stake(U256::from(5), sender);
+
+        fixture.call_snapshot_views(Address::from(fixture.bob), U256::from(10), sender);
+    }
+
+    #[test]
+    fn should_govern_staking_contract_with_proposals() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.install_governance(U256::from(100), U256::from(50), U256::from(4_000), U256::from(5_000));
+
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let staking_package = fixture.staking_contract_package_hash;
+        let governance_package = fixture.governance_contract_package_hash.unwrap();
+
+        fixture.approve_stake_token(spender, U256::from(100), sender);
+        fixture.add_time(10);
+        fixture.stake(U256::from(100), sender);
+
+        // Governance becomes the admin of the staking contract through its first proposal
+        fixture.transfer_ownership(Address::from(governance_package), Sender(fixture.ali));
+
+        fixture.add_time(10);
+        fixture.propose(staking_package, "accept_ownership", runtime_args! {}, sender);
+        fixture.vote(0, true, sender);
+
+        fixture.add_time(150);
+        fixture.execute(0, Sender(fixture.joe));
+
+        assert_eq!(fixture.owner(), Key::from(governance_package));
+
+        fixture.add_time(10);
+        fixture.propose(
+            staking_package,
+            "set_reward_rate",
+            runtime_args! {
+                "reward_token" => Key::from(fixture.reward_contract_hash),
+                "reward_rate" => U256::from(7)
+            },
+            sender
+        );
+        fixture.propose(staking_package, "pause", runtime_args! {}, sender);
+        fixture.vote(1, true, sender);
+        fixture.vote(2, true, sender);
+
+        fixture.add_time(150);
+        fixture.execute(1, Sender(fixture.joe));
+        fixture.execute(2, Sender(fixture.joe));
+
+        assert_eq!(fixture.reward_rate(), U256::from(7));
+        assert!(fixture.paused());
+    }
+
+    #[test]
+    fn should_add_chef_pool_through_governance() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.install_chef();
+        fixture.install_governance(U256::from(100), U256::from(0), U256::from(4_000), U256::from(5_000));
+
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let chef: Address = Address::from(fixture.chef_contract_package_hash.unwrap());
+        let chef_package = fixture.chef_contract_package_hash.unwrap();
+        let governance_package = fixture.governance_contract_package_hash.unwrap();
+        let partner_token = Key::from(fixture.partner_contract_hash);
+
+        fixture.approve_stake_token(spender, U256::from(100), sender);
+        fixture.add_time(10);
+        fixture.stake(U256::from(100), sender);
+
+        // Governance becomes the admin of the chef through its first proposal
+        fixture.chef_transfer_ownership(Address::from(governance_package), Sender(fixture.ali));
+
+        fixture.add_time(10);
+        fixture.propose(chef_package, "accept_ownership", runtime_args! {}, sender);
+        fixture.vote(0, true, sender);
+        fixture.add_time(101);
+        fixture.execute(0, sender);
+
+        fixture.add_time(10);
+        fixture.propose(
+            chef_package,
+            "add_pool",
+            runtime_args! {
+                "stake_token_hash" => partner_token,
+                "alloc_point" => U256::from(1)
+            },
+            sender
+        );
+        fixture.vote(1, true, sender);
+        fixture.add_time(101);
+        fixture.execute(1, sender);
+
+        assert_eq!(fixture.chef_pool_count(), 1);
+
+        // The pool added by governance takes stakes
+        fixture.approve_partner_token(chef, U256::from(5), Sender(fixture.ali));
+        fixture.chef_stake(0, U256::from(5), Sender(fixture.ali));
+
+        assert_eq!(
+            fixture.partner_token_balance_of(Key::from(chef)),
+            Some(U256::from(5))
+        );
+    }
+
+    #[should_panic(expected = "ApiError::User(1) [65537]")]
+    #[test]
+    fn should_not_add_chef_pool_after_ownership_transfer() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.install_chef();
+
+        let stake_token = Key::from(fixture.stake_contract_hash);
+
+        fixture.chef_transfer_ownership(Address::from(fixture.bob), Sender(fixture.ali));
+        fixture.chef_accept_ownership(Sender(fixture.bob));
+
+        fixture.add_pool(stake_token, U256::from(1), Sender(fixture.ali));
+    }
+
+    #[test]
+    fn should_migrate_upgraded_contract_through_governance() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.install_governance(U256::from(100), U256::from(0), U256::from(4_000), U256::from(5_000));
+
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let staking_package = fixture.staking_contract_package_hash;
+        let governance_package = fixture.governance_contract_package_hash.unwrap();
+
+        fixture.approve_stake_token(spender, U256::from(100), sender);
+        fixture.add_time(10);
+        fixture.stake(U256::from(100), sender);
+
+        fixture.transfer_ownership(Address::from(governance_package), Sender(fixture.ali));
+
+        fixture.add_time(10);
+        fixture.propose(staking_package, "accept_ownership", runtime_args! {}, sender);
+        fixture.vote(0, true, sender);
+        fixture.add_time(101);
+        fixture.execute(0, sender);
+
+        // Installer keeps adding versions, the new owner migrates them
+        fixture.upgrade_contract();
+
+        fixture.add_time(10);
+        fixture.propose(staking_package, "migrate", runtime_args! {}, sender);
+        fixture.vote(1, true, sender);
+        fixture.add_time(101);
+        fixture.execute(1, sender);
+
+        assert_eq!(fixture.contract_version(), 1);
+        assert_eq!(fixture.total_supply(), U256::from(100));
+    }
+
+    #[should_panic(expected = "ApiError::User(2005) [67541]")]
+    #[test]
+    fn should_not_execute_proposal_before_timelock() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.install_governance(U256::from(100), U256::from(50), U256::from(4_000), U256::from(5_000));
+
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let staking_package = fixture.staking_contract_package_hash;
+
+        fixture.approve_stake_token(spender, U256::from(100), sender);
+        fixture.add_time(10);
+        fixture.stake(U256::from(100), sender);
+
+        fixture.add_time(10);
+        fixture.propose(staking_package, "pause", runtime_args! {}, sender);
+        fixture.vote(0, true, sender);
+
+        fixture.add_time(120);
+        fixture.execute(0, sender);
+    }
+
+    #[test]
+    fn should_stake_only_when_allowlisted() {
+        // bob of the fixture, its account is only created by the install
+        let bob: AccountHash = PublicKey::ed25519_from_bytes([6u8; 32]).unwrap().to_account_hash();
+
+        let mut fixture = TestFixture::install_contract_with(runtime_args! {
+            ALLOWLIST_ENABLED_KEY_NAME => true,
+            ALLOWLIST_KEY_NAME => vec![Address::from(bob)]
+        });
+
+        let owner = fixture.bob;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+
+        assert!(fixture.allowlist_enabled());
+        assert!(fixture.allowlisted(Key::from(owner)));
+        assert!(!fixture.allowlisted(Key::from(fixture.joe)));
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.notify_reward_amount(U256::from(1000), U256::from(100), Sender(fixture.ali));
+
+        fixture.approve_stake_token(spender, U256::from(15), sender);
+        fixture.add_time(10);
+        fixture.stake(U256::from(10), sender);
+
+        // Removed stakers keep access to their stake and rewards
+        fixture.remove_from_allowlist(vec![Address::from(owner)], Sender(fixture.ali));
+        assert!(!fixture.allowlisted(Key::from(owner)));
+
+        fixture.add_time(10);
+        fixture.get_reward(sender);
+        fixture.withdraw(U256::from(10), sender);
+
+        assert_eq!(fixture.reward_token_balance_of(Key::from(owner)), Some(U256::from(100)));
+        assert_eq!(fixture.stake_token_balance_of(Key::from(owner)), Some(U256::from(1000)));
+
+        // Anyone stakes once the allowlist is disabled
+        fixture.set_allowlist_enabled(false, Sender(fixture.ali));
+        fixture.stake(U256::from(5), sender);
+
+        assert_eq!(fixture.total_supply(), U256::from(5));
+    }
+
+    #[should_panic(expected = "ApiError::User(41) [65577]")]
+    #[test]
+    fn should_not_stake_when_removed_from_allowlist() {
+        let mut fixture = TestFixture::install_contract_with(runtime_args! {
+            ALLOWLIST_ENABLED_KEY_NAME => true
+        });
+
+        let owner = fixture.bob;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+
+        fixture.add_to_allowlist(vec![Address::from(owner), Address::from(fixture.joe)], Sender(fixture.ali));
+        fixture.approve_stake_token(spender, U256::from(10), sender);
+        fixture.stake(U256::from(5), sender);
+
+        fixture.remove_from_allowlist(vec![Address::from(owner)], Sender(fixture.ali));
+        fixture.stake(U256::from(5), sender);
+    }
+
+    #[test]
+    fn should_stake_within_stake_limits() {
+        let mut fixture = TestFixture::install_contract_with(runtime_args! {
+            MIN_STAKE_KEY_NAME => U256::from(5),
+            MAX_STAKE_PER_ADDRESS_KEY_NAME => U256::from(20),
+            POOL_CAP_KEY_NAME => U256::from(30)
+        });
+
+        let owner = fixture.bob;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+
+        assert_eq!(fixture.stake_limits(), (U256::from(5), U256::from(20), U256::from(30)));
+
+        fixture.approve_stake_token(spender, U256::from(120), sender);
+        fixture.stake(U256::from(5), sender);
+        fixture.stake(U256::from(15), sender);
+
+        fixture.call_views(Address::from(owner), sender);
+
+        // 0 lifts each limit
+        fixture.set_stake_limits(U256::zero(), U256::zero(), U256::zero(), Sender(fixture.ali));
+        fixture.stake(U256::from(100), sender);
+
+        assert_eq!(fixture.stake_limits(), (U256::zero(), U256::zero(), U256::zero()));
+        assert_eq!(fixture.total_supply(), U256::from(120));
+    }
+
+    #[should_panic(expected = "ApiError::User(42) [65578]")]
+    #[test]
+    fn should_not_stake_below_minimum_stake() {
+        let mut fixture = TestFixture::install_contract_with(runtime_args! {
+            MIN_STAKE_KEY_NAME => U256::from(5)
+        });
+
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+
+        fixture.approve_stake_token(spender, U256::from(4), sender);
+        fixture.stake(U256::from(4), sender);
+    }
+
+    #[should_panic(expected = "ApiError::User(43) [65579]")]
+    #[test]
+    fn should_not_stake_above_per_address_cap() {
+        let mut fixture = TestFixture::install_contract_with(runtime_args! {
+            MAX_STAKE_PER_ADDRESS_KEY_NAME => U256::from(20)
+        });
+
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+
+        fixture.approve_stake_token(spender, U256::from(21), sender);
+        fixture.stake(U256::from(15), sender);
+        fixture.stake(U256::from(6), sender);
+    }
+
+    #[should_panic(expected = "ApiError::User(44) [65580]")]
+    #[test]
+    fn should_not_stake_above_pool_cap() {
+        let mut fixture = TestFixture::install_contract();
+
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+
+        fixture.set_stake_limits(U256::zero(), U256::zero(), U256::from(10), Sender(fixture.ali));
+
+        fixture.approve_stake_token(spender, U256::from(11), sender);
+        fixture.stake(U256::from(11), sender);
+    }
+
+    #[test]
+    fn should_integrate_emission_schedule_across_halvings() {
+        let mut fixture = TestFixture::install_contract();
+
+        let owner = fixture.bob;
+        let sender = Sender(fixture.bob);
+        let spender: Address = Address::from(fixture.staking_contract_package_hash);
+        let reward_token = Key::from(fixture.reward_contract_hash);
+
+        fixture.approve_stake_token(spender, U256::from(10), sender);
+        fixture.stake(U256::from(10), sender);
+
+        fixture.approve_reward_token(spender, U256::from(1000), Sender(fixture.ali));
+        fixture.fund_rewards(reward_token, U256::from(1000), Sender(fixture.ali));
+
+        // Emissions halve every 10 seconds and stop at 40
+        let emission_schedule = vec![
+            (U256::from(10), U256::from(8)),
+            (U256::from(20), U256::from(4)),
+            (U256::from(30), U256::from(2)),
+            (U256::from(40), U256::from(0)),
+        ];
+        fixture.set_emission_schedule(emission_schedule.clone(), Sender(fixture.ali));
+
+        assert_eq!(fixture.emission_schedule(), emission_schedule);
+        assert_eq!(fixture.period_finish(), U256::from(40));
+        assert_eq!(fixture.outstanding_rewards(), U256::from(140));
+
+        // First segment only starts at 10
+        let schedule_updated = fixture.event(2).unwrap();
+        assert_eq!(schedule_updated["event_type"], "EmissionScheduleUpdated");
+        assert_eq!(schedule_updated["address"], Key::from(fixture.ali).to_formatted_string());
+        assert_eq!(schedule_updated["amount"], "0");
+        assert_eq!(schedule_updated["segment_count"], "4");
+        assert_eq!(schedule_updated["reward_token"], reward_token.to_formatted_string());
+
+        // 8 * 10 + 4 * 10 + 2 * 5
+        fixture.add_time(35);
+        fixture.call_views(Address::from(owner), sender);
+        fixture.get_reward(sender);
+
+        assert_eq!(fixture.reward_token_balance_of(Key::from(owner)), Some(U256::from(130)));
+
+        fixture.add_time(100);
+        fixture.get_reward(sender);
+
+        assert_eq!(fixture.reward_token_balance_of(Key::from(owner)), Some(U256::from(140)));
+        assert_eq!(fixture.outstanding_rewards(), U256::from(0));
+    }
+
+    #[should_panic(expected = "ApiError::User(45) [65581]")]
+    #[test]
+    fn should_not_set_emission_schedule_without_end() {
+        let mut fixture = TestFixture::install_contract();
+
+        fixture.set_emission_schedule(
+            vec![
+                (U256::from(10), U256::from(8)),
+                (U256::from(20), U256::from(4)),
+            ],
+            Sender(fixture.ali)
+        );
+    }
 }
 
 fn main() {
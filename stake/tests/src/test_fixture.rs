@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use casper_erc20::Address;
 use blake2::{
     digest::{Update, VariableOutput},
@@ -7,28 +9,147 @@ use casper_engine_test_support::{Code, SessionBuilder, TestContext, TestContextB
 use casper_erc20::constants as consts;
 use casper_types::{
     account::AccountHash, ContractPackageHash,
-    bytesrepr::{FromBytes, ToBytes},
-    runtime_args, AsymmetricType, CLTyped, ContractHash, Key, PublicKey, RuntimeArgs, U256, U512,
+    bytesrepr::{Bytes, FromBytes, ToBytes},
+    runtime_args, AsymmetricType, CLTyped, CLValue, ContractHash, Key, PublicKey, RuntimeArgs, U256, U512,
 };
 
 // TODO Connect constants from 'Staking contact' folder
 const STAKING_CONTRACT_KEY_NAME: &str = "staking_contract";
-const STAKE_TOKEN_HASH_KEY_NAME: &str = "stake_token_hash";
-const REWARD_TOKEN_HASH_KEY_NAME: &str = "reward_token_hash";
+pub const STAKE_TOKEN_HASH_KEY_NAME: &str = "stake_token_hash";
+pub const REWARD_TOKEN_HASH_KEY_NAME: &str = "reward_token_hash";
 const REWARD_RATE_KEY_NAME: &str = "reward_rate";
 const LAST_UPDATE_KEY_NAME: &str = "last_update_time";
 const REWARD_PER_TOKEN_STORED_KEY_NAME: &str = "reward_per_token_stored";
+const REWARD_PER_TOKEN_DUST_KEY_NAME: &str = "reward_per_token_dust";
 const TOTAL_SUPPLY_KEY_NAME: &str = "total_supply";
+const PERIOD_FINISH_KEY_NAME: &str = "period_finish";
+const OWNER_KEY_NAME: &str = "owner";
+const PENDING_OWNER_KEY_NAME: &str = "pending_owner";
+const PAUSED_KEY_NAME: &str = "paused";
 const BALANCES_KEY_NAME: &str = "balances";
 const REWARDS_KEY_NAME: &str = "rewards";
 const USER_REWARD_PER_TOKEN_PAID_KEY_NAME: &str = "user_reward_per_token_paid";
+const EVENTS_KEY_NAME: &str = "events";
+const EVENT_COUNT_KEY_NAME: &str = "event_count";
+const EFFECTIVE_SUPPLY_KEY_NAME: &str = "effective_supply";
+const LOCK_TIERS_KEY_NAME: &str = "lock_tiers";
+const REWARD_TOKENS_KEY_NAME: &str = "reward_tokens";
+const COMPOUNDING_ENABLED_KEY_NAME: &str = "compounding_enabled";
+const CONTRACT_VERSION_KEY_NAME: &str = "contract_version";
+const STAKE_TOKEN_DECIMALS_KEY_NAME: &str = "stake_token_decimals";
+const REWARD_TOKEN_DECIMALS_KEY_NAME: &str = "reward_token_decimals";
+const REWARD_RESERVE_KEY_NAME: &str = "reward_reserve";
+const OUTSTANDING_REWARDS_KEY_NAME: &str = "outstanding_rewards";
+pub const UNBONDING_PERIOD_KEY_NAME: &str = "unbonding_period";
+pub const PENALTY_PERIOD_KEY_NAME: &str = "penalty_period";
+pub const PENALTY_RATE_KEY_NAME: &str = "penalty_rate";
+pub const TREASURY_KEY_NAME: &str = "treasury";
+pub const VESTING_PERIOD_KEY_NAME: &str = "vesting_period";
+pub const ALLOWLIST_ENABLED_KEY_NAME: &str = "allowlist_enabled";
+pub const ALLOWLIST_KEY_NAME: &str = "allowlist";
+pub const MIN_STAKE_KEY_NAME: &str = "min_stake";
+pub const MAX_STAKE_PER_ADDRESS_KEY_NAME: &str = "max_stake_per_address";
+pub const POOL_CAP_KEY_NAME: &str = "pool_cap";
+const SET_STAKE_LIMITS_ENTRY_POINT_NAME: &str = "set_stake_limits";
+const STAKE_LIMITS_ENTRY_POINT_NAME: &str = "stake_limits";
+const REMAINING_CAPACITY_ENTRY_POINT_NAME: &str = "remaining_capacity";
+const EMISSION_SCHEDULE_KEY_NAME: &str = "emission_schedule";
+const SET_EMISSION_SCHEDULE_ENTRY_POINT_NAME: &str = "set_emission_schedule";
+const EMISSION_RATES_ENTRY_POINT_NAME: &str = "emission_rates";
+const ADDRESSES_KEY_NAME: &str = "addresses";
+const ADD_TO_ALLOWLIST_ENTRY_POINT_NAME: &str = "add_to_allowlist";
+const REMOVE_FROM_ALLOWLIST_ENTRY_POINT_NAME: &str = "remove_from_allowlist";
+const SET_ALLOWLIST_ENABLED_ENTRY_POINT_NAME: &str = "set_allowlist_enabled";
+const UNBONDING_ENTRIES_KEY_NAME: &str = "unbonding_entries";
+const WEIGHTED_STAKE_TIMES_KEY_NAME: &str = "weighted_stake_times";
+const VESTING_ESCROWS_KEY_NAME: &str = "vesting_escrows";
+const VESTED_REWARDS_KEY_NAME: &str = "vested_rewards";
+const CLAIM_VESTED_ENTRY_POINT_NAME: &str = "claim_vested";
+const BOOST_TOKEN_HASH_KEY_NAME: &str = "boost_token_hash";
+const WORKING_SUPPLY_KEY_NAME: &str = "working_supply";
+const WORKING_BALANCES_KEY_NAME: &str = "working_balances";
+const SET_BOOST_TOKEN_ENTRY_POINT_NAME: &str = "set_boost_token";
+const LOCK_BOOST_ENTRY_POINT_NAME: &str = "lock_boost";
+const UNLOCK_BOOST_ENTRY_POINT_NAME: &str = "unlock_boost";
+const BALANCE_CHECKPOINTS_KEY_NAME: &str = "balance_checkpoints";
+const TOTAL_SUPPLY_CHECKPOINTS_KEY_NAME: &str = "total_supply_checkpoints";
+const BALANCE_OF_AT_ENTRY_POINT_NAME: &str = "balance_of_at";
+const TOTAL_SUPPLY_AT_ENTRY_POINT_NAME: &str = "total_supply_at";
+const TIMESTAMP_KEY_NAME: &str = "timestamp";
 const STAKE_ENTRY_POINT_NAME: &str = "stake";
 const WITHDRAW_ENTRY_POINT_NAME: &str = "withdraw";
 const GET_REWARD_ENTRY_POINT_NAME: &str = "get_reward";
+const NOTIFY_REWARD_AMOUNT_ENTRY_POINT_NAME: &str = "notify_reward_amount";
+const TRANSFER_OWNERSHIP_ENTRY_POINT_NAME: &str = "transfer_ownership";
+const ACCEPT_OWNERSHIP_ENTRY_POINT_NAME: &str = "accept_ownership";
+const SET_REWARD_RATE_ENTRY_POINT_NAME: &str = "set_reward_rate";
+const SET_STAKE_TOKEN_ENTRY_POINT_NAME: &str = "set_stake_token";
+const SET_REWARD_TOKEN_ENTRY_POINT_NAME: &str = "set_reward_token";
+const PAUSE_ENTRY_POINT_NAME: &str = "pause";
+const UNPAUSE_ENTRY_POINT_NAME: &str = "unpause";
+const EMERGENCY_WITHDRAW_ENTRY_POINT_NAME: &str = "emergency_withdraw";
+const STAKE_LOCKED_ENTRY_POINT_NAME: &str = "stake_locked";
+const UNLOCK_POSITION_ENTRY_POINT_NAME: &str = "unlock_position";
+const SET_LOCK_TIERS_ENTRY_POINT_NAME: &str = "set_lock_tiers";
+const ADD_REWARD_TOKEN_ENTRY_POINT_NAME: &str = "add_reward_token";
+const GET_REWARD_FOR_ENTRY_POINT_NAME: &str = "get_reward_for";
+const COMPOUND_ENTRY_POINT_NAME: &str = "compound";
+const STAKE_FOR_ENTRY_POINT_NAME: &str = "stake_for";
+const WITHDRAW_TO_ENTRY_POINT_NAME: &str = "withdraw_to";
+const GET_REWARD_TO_ENTRY_POINT_NAME: &str = "get_reward_to";
+const MIGRATE_ENTRY_POINT_NAME: &str = "migrate";
+const FUND_REWARDS_ENTRY_POINT_NAME: &str = "fund_rewards";
+const RECOVER_TOKENS_ENTRY_POINT_NAME: &str = "recover_tokens";
+const REQUEST_WITHDRAW_ENTRY_POINT_NAME: &str = "request_withdraw";
+const CLAIM_UNBONDED_ENTRY_POINT_NAME: &str = "claim_unbonded";
+const BALANCE_OF_ENTRY_POINT_NAME: &str = "balance_of";
+const EARNED_ENTRY_POINT_NAME: &str = "earned";
+const REWARD_PER_TOKEN_ENTRY_POINT_NAME: &str = "reward_per_token";
+const TOTAL_SUPPLY_ENTRY_POINT_NAME: &str = "total_supply";
+const REWARD_RATE_ENTRY_POINT_NAME: &str = "reward_rate";
+const LAST_TIME_REWARD_APPLICABLE_ENTRY_POINT_NAME: &str = "last_time_reward_applicable";
+const EFFECTIVE_BALANCE_OF_ENTRY_POINT_NAME: &str = "effective_balance_of";
+const LOCKED_BALANCE_OF_ENTRY_POINT_NAME: &str = "locked_balance_of";
+const REWARD_TOKENS_ENTRY_POINT_NAME: &str = "reward_tokens";
 const AMOUNT_KEY_NAME:  &str = "amount";
+const DURATION_KEY_NAME: &str = "duration";
+const NEW_OWNER_KEY_NAME: &str = "new_owner";
+const ADDRESS_KEY_NAME: &str = "address";
+const LOCK_DURATION_KEY_NAME: &str = "lock_duration";
+const POSITION_ID_KEY_NAME: &str = "position_id";
+const REWARD_TOKEN_KEY_NAME: &str = "reward_token";
+const TOKEN_KEY_NAME: &str = "token";
+const BENEFICIARY_KEY_NAME: &str = "beneficiary";
+const RECIPIENT_KEY_NAME: &str = "recipient";
+const CHEF_CONTRACT_KEY_NAME: &str = "chef_contract";
+const ADD_POOL_ENTRY_POINT_NAME: &str = "add_pool";
+const SET_POOL_ENTRY_POINT_NAME: &str = "set_pool";
+const POOL_ID_KEY_NAME: &str = "pool_id";
+const ALLOC_POINT_KEY_NAME: &str = "alloc_point";
+const GOVERNANCE_CONTRACT_KEY_NAME: &str = "governance_contract";
+const STAKING_CONTRACT_PACKAGE_HASH_KEY_NAME: &str = "staking_contract_package_hash";
+const VOTING_PERIOD_KEY_NAME: &str = "voting_period";
+const TIMELOCK_DELAY_KEY_NAME: &str = "timelock_delay";
+const QUORUM_KEY_NAME: &str = "quorum";
+const THRESHOLD_KEY_NAME: &str = "threshold";
+const PROPOSE_ENTRY_POINT_NAME: &str = "propose";
+const VOTE_ENTRY_POINT_NAME: &str = "vote";
+const EXECUTE_ENTRY_POINT_NAME: &str = "execute";
+const TARGET_KEY_NAME: &str = "target";
+const ENTRY_POINT_KEY_NAME: &str = "entry_point";
+const ARGS_KEY_NAME: &str = "args";
+const PROPOSAL_ID_KEY_NAME: &str = "proposal_id";
+const SUPPORT_KEY_NAME: &str = "support";
 
 const CONTRACT_FILE: &str = "staking_contract.wasm";
 const CONTRACT_NAME: &str = "stake_wcspr_reward_picas";
+const ANOTHER_CONTRACT_NAME: &str = "stake_wcspr_reward_picas_2";
+
+const CHEF_CONTRACT_FILE: &str = "chef_contract.wasm";
+const CHEF_CONTRACT_NAME: &str = "picas_chef";
+
+const GOVERNANCE_CONTRACT_FILE: &str = "governance_contract.wasm";
+const GOVERNANCE_CONTRACT_NAME: &str = "picas_governance";
 
 const STAKE_CONTRACT_FILE: &str = "wcspr.wasm";
 const STAKE_CONTRACT_KEY_NAME: &str = "wcspr_token";
@@ -52,16 +173,35 @@ pub struct TestFixture {
     pub bob: AccountHash,
     pub joe: AccountHash,
     pub reward_contract_hash: ContractHash,
+    pub partner_contract_hash: ContractHash,
     pub stake_contract_hash: ContractHash,
     pub contract_name: String,
     pub staking_contract_hash: ContractHash,
     pub staking_contract_package_hash: ContractPackageHash,
+    pub chef_contract_hash: Option<ContractHash>,
+    pub chef_contract_package_hash: Option<ContractPackageHash>,
+    pub governance_contract_hash: Option<ContractHash>,
+    pub governance_contract_package_hash: Option<ContractPackageHash>,
     pub current_time: u64
 }
 
 impl TestFixture {
 
     pub fn install_contract() -> TestFixture {
+        TestFixture::install_contract_with(runtime_args! {})
+    }
+
+    /// Same as `install_contract`, `install_args` replace the default settings of the Staking contract
+    pub fn install_contract_with(install_args: RuntimeArgs) -> TestFixture {
+        TestFixture::install(install_args, false)
+    }
+
+    /// Same as `install_contract_with`, the pool stakes its own Reward Token
+    pub fn install_single_token_contract_with(install_args: RuntimeArgs) -> TestFixture {
+        TestFixture::install(install_args, true)
+    }
+
+    fn install(install_args: RuntimeArgs, stake_reward_token: bool) -> TestFixture {
         let ali = PublicKey::ed25519_from_bytes([3u8; 32]).unwrap();
         let bob = PublicKey::ed25519_from_bytes([6u8; 32]).unwrap();
         let joe = PublicKey::ed25519_from_bytes([9u8; 32]).unwrap();
@@ -100,21 +240,58 @@ impl TestFixture {
             .build();
         context.run(session);
 
+        // Deploy Partner token, a second reward token
+        let session_code = Code::from(REWARD_CONTRACT_FILE);
+        let session_args = runtime_args! {
+            consts::NAME_RUNTIME_ARG_NAME => "PARTNER Token",
+            consts::SYMBOL_RUNTIME_ARG_NAME => "PARTNER",
+            consts::DECIMALS_RUNTIME_ARG_NAME => 9 as u8,
+            consts::TOTAL_SUPPLY_RUNTIME_ARG_NAME => casper_types::U256::from(1000)
+        };
+        let session = SessionBuilder::new(session_code, session_args)
+            .with_address(joe.to_account_hash())
+            .with_authorization_keys(&[joe.to_account_hash()])
+            .build();
+        context.run(session);
+
+        // Partner tokens are funded by the owner of the staking contract
+        let partner_contract_hash: ContractHash = context.get_account(joe.to_account_hash()).unwrap().named_keys().get(REWARD_CONTRACT_KEY_NAME).unwrap().normalize().into_hash().unwrap().into();
+        let session_code = Code::Hash(partner_contract_hash.value(), consts::TRANSFER_ENTRY_POINT_NAME.to_string());
+        let session_args = runtime_args! {
+            consts::RECIPIENT_RUNTIME_ARG_NAME => Key::from(ali.to_account_hash()),
+            consts::AMOUNT_RUNTIME_ARG_NAME => casper_types::U256::from(1000)
+        };
+        let session = SessionBuilder::new(session_code, session_args)
+            .with_address(joe.to_account_hash())
+            .with_authorization_keys(&[joe.to_account_hash()])
+            .build();
+        context.run(session);
+
         // Deploy Staking Contract
         let reward_contract_hash: ContractHash = context.get_account(ali.to_account_hash()).unwrap().named_keys().get(REWARD_CONTRACT_KEY_NAME).unwrap().normalize().into_hash().unwrap().into();
         let reward_token: Key = Key::from(reward_contract_hash);
 
         let stake_contract_hash: ContractHash = context.get_account(bob.to_account_hash()).unwrap().named_keys().get(STAKE_CONTRACT_KEY_NAME).unwrap().normalize().into_hash().unwrap().into();
-        let stake_token: Key = Key::from(stake_contract_hash);
+        let stake_token: Key = if stake_reward_token { reward_token } else { Key::from(stake_contract_hash) };
 
         let session_code = Code::from(CONTRACT_FILE);
-        let session_args = runtime_args! {
+        let mut session_args: BTreeMap<String, CLValue> = runtime_args! {
             STAKE_TOKEN_HASH_KEY_NAME => stake_token,
             REWARD_TOKEN_HASH_KEY_NAME => reward_token,
             STAKING_CONTRACT_KEY_NAME => CONTRACT_NAME.to_string(),
-            REWARD_RATE_KEY_NAME => U256::from(20)
-        };
-        let session = SessionBuilder::new(session_code, session_args)
+            UNBONDING_PERIOD_KEY_NAME => U256::zero(),
+            PENALTY_PERIOD_KEY_NAME => U256::zero(),
+            PENALTY_RATE_KEY_NAME => U256::zero(),
+            TREASURY_KEY_NAME => Option::<Address>::None,
+            VESTING_PERIOD_KEY_NAME => U256::zero(),
+            ALLOWLIST_ENABLED_KEY_NAME => false,
+            ALLOWLIST_KEY_NAME => Vec::<Address>::new(),
+            MIN_STAKE_KEY_NAME => U256::zero(),
+            MAX_STAKE_PER_ADDRESS_KEY_NAME => U256::zero(),
+            POOL_CAP_KEY_NAME => U256::zero()
+        }.into();
+        session_args.extend(BTreeMap::<String, CLValue>::from(install_args));
+        let session = SessionBuilder::new(session_code, RuntimeArgs::from(session_args))
             .with_address(ali.to_account_hash())
             .with_authorization_keys(&[ali.to_account_hash()])
             .build();
@@ -134,13 +311,107 @@ impl TestFixture {
             joe: joe.to_account_hash(),
             stake_contract_hash: stake_contract_hash,
             reward_contract_hash: reward_contract_hash,
+            partner_contract_hash: partner_contract_hash,
             contract_name: CONTRACT_NAME.to_string(),
             staking_contract_hash: staking_contract_hash,
             staking_contract_package_hash: staking_contract_package_hash,
+            chef_contract_hash: None,
+            chef_contract_package_hash: None,
+            governance_contract_hash: None,
+            governance_contract_package_hash: None,
             current_time: 0 as u64
         }
     }
 
+    /// Installs a second Staking contract from ali next to the first one,
+    /// `install_args` replace the settings and tokens of the first one
+    pub fn install_another_contract_with(&mut self, install_args: RuntimeArgs) {
+        let session_code = Code::from(CONTRACT_FILE);
+        let mut session_args: BTreeMap<String, CLValue> = runtime_args! {
+            STAKE_TOKEN_HASH_KEY_NAME => Key::from(self.stake_contract_hash),
+            REWARD_TOKEN_HASH_KEY_NAME => Key::from(self.reward_contract_hash),
+            STAKING_CONTRACT_KEY_NAME => ANOTHER_CONTRACT_NAME.to_string(),
+            UNBONDING_PERIOD_KEY_NAME => U256::zero(),
+            PENALTY_PERIOD_KEY_NAME => U256::zero(),
+            PENALTY_RATE_KEY_NAME => U256::zero(),
+            TREASURY_KEY_NAME => Option::<Address>::None,
+            VESTING_PERIOD_KEY_NAME => U256::zero(),
+            ALLOWLIST_ENABLED_KEY_NAME => false,
+            ALLOWLIST_KEY_NAME => Vec::<Address>::new(),
+            MIN_STAKE_KEY_NAME => U256::zero(),
+            MAX_STAKE_PER_ADDRESS_KEY_NAME => U256::zero(),
+            POOL_CAP_KEY_NAME => U256::zero()
+        }.into();
+        session_args.extend(BTreeMap::<String, CLValue>::from(install_args));
+        let session = SessionBuilder::new(session_code, RuntimeArgs::from(session_args))
+            .with_address(self.ali)
+            .with_authorization_keys(&[self.ali])
+            .build();
+        self.context.run(session);
+    }
+
+    /// Installs the Chef contract paying Reward Token, owned by ali
+    pub fn install_chef(&mut self) {
+        let session_code = Code::from(CHEF_CONTRACT_FILE);
+        let session_args = runtime_args! {
+            REWARD_TOKEN_HASH_KEY_NAME => Key::from(self.reward_contract_hash),
+            CHEF_CONTRACT_KEY_NAME => CHEF_CONTRACT_NAME.to_string()
+        };
+        let session = SessionBuilder::new(session_code, session_args)
+            .with_address(self.ali)
+            .with_authorization_keys(&[self.ali])
+            .with_block_time(self.current_time)
+            .build();
+        self.context.run(session);
+
+        let contract_package_hash_key_name = String::from(CHEF_CONTRACT_NAME) + "_package_hash";
+        let named_keys = self.context.get_account(self.ali).unwrap().named_keys().clone();
+
+        self.chef_contract_hash = Some(named_keys.get(CHEF_CONTRACT_NAME).unwrap().normalize().into_hash().unwrap().into());
+        self.chef_contract_package_hash = Some(named_keys.get(&contract_package_hash_key_name).unwrap().normalize().into_hash().unwrap().into());
+    }
+
+    /// Installs the Governance contract voting with the stake of the Staking contract
+    pub fn install_governance(&mut self, voting_period: U256, timelock_delay: U256, quorum: U256, threshold: U256) {
+        let session_code = Code::from(GOVERNANCE_CONTRACT_FILE);
+        let session_args = runtime_args! {
+            GOVERNANCE_CONTRACT_KEY_NAME => GOVERNANCE_CONTRACT_NAME.to_string(),
+            STAKING_CONTRACT_PACKAGE_HASH_KEY_NAME => Key::from(self.staking_contract_package_hash),
+            VOTING_PERIOD_KEY_NAME => voting_period,
+            TIMELOCK_DELAY_KEY_NAME => timelock_delay,
+            QUORUM_KEY_NAME => quorum,
+            THRESHOLD_KEY_NAME => threshold
+        };
+        let session = SessionBuilder::new(session_code, session_args)
+            .with_address(self.ali)
+            .with_authorization_keys(&[self.ali])
+            .with_block_time(self.current_time)
+            .build();
+        self.context.run(session);
+
+        let contract_package_hash_key_name = String::from(GOVERNANCE_CONTRACT_NAME) + "_package_hash";
+        let named_keys = self.context.get_account(self.ali).unwrap().named_keys().clone();
+
+        self.governance_contract_hash = Some(named_keys.get(GOVERNANCE_CONTRACT_NAME).unwrap().normalize().into_hash().unwrap().into());
+        self.governance_contract_package_hash = Some(named_keys.get(&contract_package_hash_key_name).unwrap().normalize().into_hash().unwrap().into());
+    }
+
+    /// Runs the install session again from ali, adding a new version of the Staking contract
+    pub fn upgrade_contract(&mut self) {
+        let session_code = Code::from(CONTRACT_FILE);
+        let session_args = runtime_args! {
+            STAKING_CONTRACT_KEY_NAME => CONTRACT_NAME.to_string()
+        };
+        let session = SessionBuilder::new(session_code, session_args)
+            .with_address(self.ali)
+            .with_authorization_keys(&[self.ali])
+            .with_block_time(self.current_time)
+            .build();
+        self.context.run(session);
+
+        self.staking_contract_hash = self.context.get_account(self.ali).unwrap().named_keys().get(CONTRACT_NAME).unwrap().normalize().into_hash().unwrap().into();
+    }
+
     pub fn add_time(&mut self, step: u64) {
         self.current_time += step;
     }
@@ -190,11 +461,197 @@ impl TestFixture {
             .unwrap()
     }
 
+    pub fn reward_per_token_dust(&self) -> U256 {
+        self.query_contract(REWARD_PER_TOKEN_DUST_KEY_NAME)
+            .unwrap()
+    }
+
     pub fn total_supply(&self) -> U256 {
         self.query_contract(TOTAL_SUPPLY_KEY_NAME)
             .unwrap()
     }
 
+    pub fn period_finish(&self) -> U256 {
+        self.query_contract(PERIOD_FINISH_KEY_NAME)
+            .unwrap()
+    }
+
+    pub fn owner(&self) -> Key {
+        self.query_contract(OWNER_KEY_NAME)
+            .unwrap()
+    }
+
+    pub fn pending_owner(&self) -> Option<Key> {
+        self.query_contract(PENDING_OWNER_KEY_NAME)
+            .unwrap()
+    }
+
+    pub fn paused(&self) -> bool {
+        self.query_contract(PAUSED_KEY_NAME)
+            .unwrap()
+    }
+
+    pub fn reward_tokens(&self) -> Vec<Key> {
+        self.query_contract(REWARD_TOKENS_KEY_NAME)
+            .unwrap()
+    }
+
+    pub fn compounding_enabled(&self) -> bool {
+        self.query_contract(COMPOUNDING_ENABLED_KEY_NAME)
+            .unwrap()
+    }
+
+    pub fn contract_version(&self) -> u32 {
+        self.query_contract(CONTRACT_VERSION_KEY_NAME)
+            .unwrap()
+    }
+
+    pub fn stake_token_decimals(&self) -> u8 {
+        self.query_contract(STAKE_TOKEN_DECIMALS_KEY_NAME)
+            .unwrap()
+    }
+
+    pub fn reward_token_decimals(&self) -> u8 {
+        self.query_contract(REWARD_TOKEN_DECIMALS_KEY_NAME)
+            .unwrap()
+    }
+
+    pub fn reward_reserve(&self) -> U256 {
+        self.query_contract(REWARD_RESERVE_KEY_NAME)
+            .unwrap()
+    }
+
+    pub fn outstanding_rewards(&self) -> U256 {
+        self.query_contract(OUTSTANDING_REWARDS_KEY_NAME)
+            .unwrap()
+    }
+
+    pub fn treasury(&self) -> Option<Address> {
+        self.query_contract(TREASURY_KEY_NAME)
+            .unwrap()
+    }
+
+    pub fn unbonding_entries(&self, account: Key) -> Vec<(U256, U256)> {
+        let item_key = base64::encode(&account.to_bytes().unwrap());
+
+        let key = Key::Hash(self.staking_contract_hash.value());
+        self.context
+            .query_dictionary_item(key, Some(UNBONDING_ENTRIES_KEY_NAME.to_string()), item_key)
+            .ok()
+            .map(|value| value.into_t().unwrap())
+            .unwrap_or_default()
+    }
+
+    pub fn weighted_stake_time(&self, account: Key) -> U256 {
+        let item_key = base64::encode(&account.to_bytes().unwrap());
+
+        let key = Key::Hash(self.staking_contract_hash.value());
+        self.context
+            .query_dictionary_item(key, Some(WEIGHTED_STAKE_TIMES_KEY_NAME.to_string()), item_key)
+            .ok()
+            .map(|value| value.into_t().unwrap())
+            .unwrap_or_default()
+    }
+
+    pub fn vesting_escrow(&self, account: Key) -> Vec<(U256, U256, U256)> {
+        let item_key = base64::encode(&account.to_bytes().unwrap());
+
+        let key = Key::Hash(self.staking_contract_hash.value());
+        self.context
+            .query_dictionary_item(key, Some(VESTING_ESCROWS_KEY_NAME.to_string()), item_key)
+            .ok()
+            .map(|value| value.into_t().unwrap())
+            .unwrap_or_default()
+    }
+
+    pub fn vested_rewards(&self, account: Key) -> U256 {
+        let item_key = base64::encode(&account.to_bytes().unwrap());
+
+        let key = Key::Hash(self.staking_contract_hash.value());
+        self.context
+            .query_dictionary_item(key, Some(VESTED_REWARDS_KEY_NAME.to_string()), item_key)
+            .ok()
+            .map(|value| value.into_t().unwrap())
+            .unwrap_or_default()
+    }
+
+    pub fn allowlisted(&self, account: Key) -> bool {
+        let item_key = base64::encode(&account.to_bytes().unwrap());
+
+        let key = Key::Hash(self.staking_contract_hash.value());
+        self.context
+            .query_dictionary_item(key, Some(ALLOWLIST_KEY_NAME.to_string()), item_key)
+            .ok()
+            .map(|value| value.into_t().unwrap())
+            .unwrap_or_default()
+    }
+
+    pub fn allowlist_enabled(&self) -> bool {
+        self.query_contract(ALLOWLIST_ENABLED_KEY_NAME)
+            .unwrap()
+    }
+
+    /// (minimum stake, per-address cap, pool cap)
+    pub fn stake_limits(&self) -> (U256, U256, U256) {
+        (
+            self.query_contract(MIN_STAKE_KEY_NAME).unwrap(),
+            self.query_contract(MAX_STAKE_PER_ADDRESS_KEY_NAME).unwrap(),
+            self.query_contract(POOL_CAP_KEY_NAME).unwrap()
+        )
+    }
+
+    pub fn emission_schedule(&self) -> Vec<(U256, U256)> {
+        self.query_contract(EMISSION_SCHEDULE_KEY_NAME)
+            .unwrap()
+    }
+
+    pub fn working_balance(&self, account: Key) -> U256 {
+        let item_key = base64::encode(&account.to_bytes().unwrap());
+
+        let key = Key::Hash(self.staking_contract_hash.value());
+        self.context
+            .query_dictionary_item(key, Some(WORKING_BALANCES_KEY_NAME.to_string()), item_key)
+            .ok()
+            .map(|value| value.into_t().unwrap())
+            .unwrap_or_default()
+    }
+
+    pub fn working_supply(&self) -> U256 {
+        self.query_contract(WORKING_SUPPLY_KEY_NAME)
+            .unwrap()
+    }
+
+    /// (block time, balance) of the checkpoint `index` of `account`
+    pub fn balance_checkpoint(&self, account: Key, index: u32) -> Option<(U256, U256)> {
+        let mut preimage = account.to_bytes().unwrap();
+        preimage.append(&mut index.to_bytes().unwrap());
+        let item_key = base64::encode(&preimage);
+
+        let key = Key::Hash(self.staking_contract_hash.value());
+        let value = self
+            .context
+            .query_dictionary_item(key, Some(BALANCE_CHECKPOINTS_KEY_NAME.to_string()), item_key)
+            .ok()?;
+
+        Some(value.into_t().unwrap())
+    }
+
+    /// (block time, total supply) of the checkpoint `index`
+    pub fn total_supply_checkpoint(&self, index: u32) -> Option<(U256, U256)> {
+        let key = Key::Hash(self.staking_contract_hash.value());
+        let value = self
+            .context
+            .query_dictionary_item(key, Some(TOTAL_SUPPLY_CHECKPOINTS_KEY_NAME.to_string()), index.to_string())
+            .ok()?;
+
+        Some(value.into_t().unwrap())
+    }
+
+    pub fn effective_supply(&self) -> U256 {
+        self.query_contract(EFFECTIVE_SUPPLY_KEY_NAME)
+            .unwrap()
+    }
+
     fn call(&mut self, sender: Sender, contract_hash: ContractHash, method: &str, args: RuntimeArgs) {
         let Sender(address) = sender;
         let code = Code::Hash(contract_hash.value(), method.to_string());
@@ -217,6 +674,33 @@ impl TestFixture {
         );
     }
 
+    pub fn stake_for(&mut self, beneficiary: Address, amount: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            STAKE_FOR_ENTRY_POINT_NAME,
+            runtime_args! {
+                BENEFICIARY_KEY_NAME => beneficiary,
+                AMOUNT_KEY_NAME => amount
+            },
+        );
+    }
+
+    pub fn event_count(&self) -> u64 {
+        self.query_contract(EVENT_COUNT_KEY_NAME)
+            .unwrap()
+    }
+
+    pub fn event(&self, index: u64) -> Option<BTreeMap<String, String>> {
+        let key = Key::Hash(self.staking_contract_hash.value());
+        let value = self
+            .context
+            .query_dictionary_item(key, Some(EVENTS_KEY_NAME.to_string()), index.to_string())
+            .ok()?;
+
+        Some(value.into_t::<BTreeMap<String, String>>().unwrap())
+    }
+
     pub fn stake_token_balance_of(&self, account: Key) -> Option<U256> {
         let item_key = base64::encode(&account.to_bytes().unwrap());
 
@@ -241,6 +725,18 @@ impl TestFixture {
         Some(value.into_t::<U256>().unwrap())
     }
 
+    pub fn partner_token_balance_of(&self, account: Key) -> Option<U256> {
+        let item_key = base64::encode(&account.to_bytes().unwrap());
+
+        let key = Key::Hash(self.partner_contract_hash.value());
+        let value = self
+            .context
+            .query_dictionary_item(key, Some(consts::BALANCES_KEY_NAME.to_string()), item_key)
+            .ok()?;
+
+        Some(value.into_t::<U256>().unwrap())
+    }
+
     pub fn approve_stake_token(&mut self, spender: Address, amount: U256, sender: Sender) {
         self.call(
             sender,
@@ -253,6 +749,30 @@ impl TestFixture {
         );
     }
 
+    pub fn approve_reward_token(&mut self, spender: Address, amount: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.reward_contract_hash,
+            consts::APPROVE_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::SPENDER_RUNTIME_ARG_NAME => spender,
+                consts::AMOUNT_RUNTIME_ARG_NAME => amount
+            },
+        );
+    }
+
+    pub fn approve_partner_token(&mut self, spender: Address, amount: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.partner_contract_hash,
+            consts::APPROVE_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::SPENDER_RUNTIME_ARG_NAME => spender,
+                consts::AMOUNT_RUNTIME_ARG_NAME => amount
+            },
+        );
+    }
+
     pub fn allowance_stake_token(&self, owner: Key, spender: Key) -> Option<U256> {
         let mut preimage = Vec::new();
         preimage.append(&mut owner.to_bytes().unwrap());
@@ -274,6 +794,18 @@ impl TestFixture {
         Some(value.into_t::<U256>().unwrap())
     }
     
+    pub fn transfer_stake_token(&mut self, recipient: Key, amount: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.stake_contract_hash,
+            consts::TRANSFER_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::RECIPIENT_RUNTIME_ARG_NAME => recipient,
+                consts::AMOUNT_RUNTIME_ARG_NAME => amount
+            },
+        );
+    }
+
     pub fn transfer_reward_token(&mut self, recipient: Key, amount: U256, sender: Sender) {
         self.call(
             sender,
@@ -297,15 +829,596 @@ impl TestFixture {
         );
     }
 
-    pub fn get_reward(&mut self, sender: Sender) {
+    pub fn request_withdraw(&mut self, amount: U256, sender: Sender) {
         self.call(
             sender,
             self.staking_contract_hash,
-            GET_REWARD_ENTRY_POINT_NAME,
+            REQUEST_WITHDRAW_ENTRY_POINT_NAME,
+            runtime_args! {
+                AMOUNT_KEY_NAME => amount
+            },
+        );
+    }
+
+    pub fn claim_unbonded(&mut self, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            CLAIM_UNBONDED_ENTRY_POINT_NAME,
+            runtime_args! {},
+        );
+    }
+
+    pub fn claim_vested(&mut self, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            CLAIM_VESTED_ENTRY_POINT_NAME,
             runtime_args! {},
         );
     }
 
+    pub fn set_boost_token(&mut self, boost_token: Key, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            SET_BOOST_TOKEN_ENTRY_POINT_NAME,
+            runtime_args! {
+                BOOST_TOKEN_HASH_KEY_NAME => boost_token
+            },
+        );
+    }
+
+    pub fn lock_boost(&mut self, amount: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            LOCK_BOOST_ENTRY_POINT_NAME,
+            runtime_args! {
+                AMOUNT_KEY_NAME => amount
+            },
+        );
+    }
+
+    pub fn unlock_boost(&mut self, amount: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            UNLOCK_BOOST_ENTRY_POINT_NAME,
+            runtime_args! {
+                AMOUNT_KEY_NAME => amount
+            },
+        );
+    }
+
+    pub fn withdraw_to(&mut self, recipient: Address, amount: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            WITHDRAW_TO_ENTRY_POINT_NAME,
+            runtime_args! {
+                RECIPIENT_KEY_NAME => recipient,
+                AMOUNT_KEY_NAME => amount
+            },
+        );
+    }
+
+    pub fn get_reward(&mut self, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            GET_REWARD_ENTRY_POINT_NAME,
+            runtime_args! {},
+        );
+    }
+
+    pub fn get_reward_to(&mut self, recipient: Address, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            GET_REWARD_TO_ENTRY_POINT_NAME,
+            runtime_args! {
+                RECIPIENT_KEY_NAME => recipient
+            },
+        );
+    }
+
+    pub fn get_reward_for(&mut self, reward_token: Key, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            GET_REWARD_FOR_ENTRY_POINT_NAME,
+            runtime_args! {
+                REWARD_TOKEN_KEY_NAME => reward_token
+            },
+        );
+    }
+
+    pub fn compound(&mut self, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            COMPOUND_ENTRY_POINT_NAME,
+            runtime_args! {},
+        );
+    }
+
+    pub fn emergency_withdraw(&mut self, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            EMERGENCY_WITHDRAW_ENTRY_POINT_NAME,
+            runtime_args! {},
+        );
+    }
+
+    pub fn stake_locked(&mut self, amount: U256, lock_duration: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            STAKE_LOCKED_ENTRY_POINT_NAME,
+            runtime_args! {
+                AMOUNT_KEY_NAME => amount,
+                LOCK_DURATION_KEY_NAME => lock_duration
+            },
+        );
+    }
+
+    pub fn unlock_position(&mut self, address: Address, position_id: u32, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            UNLOCK_POSITION_ENTRY_POINT_NAME,
+            runtime_args! {
+                ADDRESS_KEY_NAME => address,
+                POSITION_ID_KEY_NAME => position_id
+            },
+        );
+    }
+
+    pub fn set_lock_tiers(&mut self, lock_tiers: Vec<(U256, U256)>, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            SET_LOCK_TIERS_ENTRY_POINT_NAME,
+            runtime_args! {
+                LOCK_TIERS_KEY_NAME => lock_tiers
+            },
+        );
+    }
+
+    pub fn add_to_allowlist(&mut self, addresses: Vec<Address>, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            ADD_TO_ALLOWLIST_ENTRY_POINT_NAME,
+            runtime_args! {
+                ADDRESSES_KEY_NAME => addresses
+            },
+        );
+    }
+
+    pub fn remove_from_allowlist(&mut self, addresses: Vec<Address>, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            REMOVE_FROM_ALLOWLIST_ENTRY_POINT_NAME,
+            runtime_args! {
+                ADDRESSES_KEY_NAME => addresses
+            },
+        );
+    }
+
+    pub fn set_allowlist_enabled(&mut self, allowlist_enabled: bool, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            SET_ALLOWLIST_ENABLED_ENTRY_POINT_NAME,
+            runtime_args! {
+                ALLOWLIST_ENABLED_KEY_NAME => allowlist_enabled
+            },
+        );
+    }
+
+    pub fn set_stake_limits(&mut self, min_stake: U256, max_stake_per_address: U256, pool_cap: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            SET_STAKE_LIMITS_ENTRY_POINT_NAME,
+            runtime_args! {
+                MIN_STAKE_KEY_NAME => min_stake,
+                MAX_STAKE_PER_ADDRESS_KEY_NAME => max_stake_per_address,
+                POOL_CAP_KEY_NAME => pool_cap
+            },
+        );
+    }
+
+    pub fn notify_reward_amount(&mut self, amount: U256, duration: U256, sender: Sender) {
+        self.notify_reward_amount_for(Key::from(self.reward_contract_hash), amount, duration, sender);
+    }
+
+    pub fn notify_reward_amount_for(&mut self, reward_token: Key, amount: U256, duration: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            NOTIFY_REWARD_AMOUNT_ENTRY_POINT_NAME,
+            runtime_args! {
+                REWARD_TOKEN_KEY_NAME => reward_token,
+                AMOUNT_KEY_NAME => amount,
+                DURATION_KEY_NAME => duration
+            },
+        );
+    }
+
+    pub fn fund_rewards(&mut self, reward_token: Key, amount: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            FUND_REWARDS_ENTRY_POINT_NAME,
+            runtime_args! {
+                REWARD_TOKEN_KEY_NAME => reward_token,
+                AMOUNT_KEY_NAME => amount
+            },
+        );
+    }
+
+    pub fn recover_tokens(&mut self, token: Key, amount: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            RECOVER_TOKENS_ENTRY_POINT_NAME,
+            runtime_args! {
+                TOKEN_KEY_NAME => token,
+                AMOUNT_KEY_NAME => amount
+            },
+        );
+    }
+
+    pub fn transfer_ownership(&mut self, new_owner: Address, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            TRANSFER_OWNERSHIP_ENTRY_POINT_NAME,
+            runtime_args! {
+                NEW_OWNER_KEY_NAME => new_owner
+            },
+        );
+    }
+
+    pub fn accept_ownership(&mut self, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            ACCEPT_OWNERSHIP_ENTRY_POINT_NAME,
+            runtime_args! {},
+        );
+    }
+
+    pub fn set_reward_rate(&mut self, reward_rate: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            SET_REWARD_RATE_ENTRY_POINT_NAME,
+            runtime_args! {
+                REWARD_TOKEN_KEY_NAME => Key::from(self.reward_contract_hash),
+                REWARD_RATE_KEY_NAME => reward_rate
+            },
+        );
+    }
+
+    pub fn set_emission_schedule(&mut self, emission_schedule: Vec<(U256, U256)>, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            SET_EMISSION_SCHEDULE_ENTRY_POINT_NAME,
+            runtime_args! {
+                REWARD_TOKEN_KEY_NAME => Key::from(self.reward_contract_hash),
+                EMISSION_SCHEDULE_KEY_NAME => emission_schedule
+            },
+        );
+    }
+
+    pub fn set_stake_token(&mut self, stake_token: Key, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            SET_STAKE_TOKEN_ENTRY_POINT_NAME,
+            runtime_args! {
+                STAKE_TOKEN_HASH_KEY_NAME => stake_token
+            },
+        );
+    }
+
+    /// Replaces the Reward Token given at install by `reward_token`
+    pub fn set_reward_token(&mut self, reward_token: Key, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            SET_REWARD_TOKEN_ENTRY_POINT_NAME,
+            runtime_args! {
+                REWARD_TOKEN_KEY_NAME => Key::from(self.reward_contract_hash),
+                REWARD_TOKEN_HASH_KEY_NAME => reward_token
+            },
+        );
+    }
+
+    pub fn add_reward_token(&mut self, reward_token: Key, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            ADD_REWARD_TOKEN_ENTRY_POINT_NAME,
+            runtime_args! {
+                REWARD_TOKEN_KEY_NAME => reward_token
+            },
+        );
+    }
+
+    pub fn migrate(&mut self, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            MIGRATE_ENTRY_POINT_NAME,
+            runtime_args! {},
+        );
+    }
+
+    pub fn pause(&mut self, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            PAUSE_ENTRY_POINT_NAME,
+            runtime_args! {},
+        );
+    }
+
+    pub fn unpause(&mut self, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            UNPAUSE_ENTRY_POINT_NAME,
+            runtime_args! {},
+        );
+    }
+
+    pub fn add_pool(&mut self, stake_token: Key, alloc_point: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.chef_contract_hash.unwrap(),
+            ADD_POOL_ENTRY_POINT_NAME,
+            runtime_args! {
+                STAKE_TOKEN_HASH_KEY_NAME => stake_token,
+                ALLOC_POINT_KEY_NAME => alloc_point
+            },
+        );
+    }
+
+    pub fn set_pool(&mut self, pool_id: u32, alloc_point: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.chef_contract_hash.unwrap(),
+            SET_POOL_ENTRY_POINT_NAME,
+            runtime_args! {
+                POOL_ID_KEY_NAME => pool_id,
+                ALLOC_POINT_KEY_NAME => alloc_point
+            },
+        );
+    }
+
+    pub fn chef_notify_reward_amount(&mut self, amount: U256, duration: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.chef_contract_hash.unwrap(),
+            NOTIFY_REWARD_AMOUNT_ENTRY_POINT_NAME,
+            runtime_args! {
+                AMOUNT_KEY_NAME => amount,
+                DURATION_KEY_NAME => duration
+            },
+        );
+    }
+
+    pub fn chef_set_reward_rate(&mut self, reward_rate: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.chef_contract_hash.unwrap(),
+            SET_REWARD_RATE_ENTRY_POINT_NAME,
+            runtime_args! {
+                REWARD_RATE_KEY_NAME => reward_rate
+            },
+        );
+    }
+
+    pub fn chef_transfer_ownership(&mut self, new_owner: Address, sender: Sender) {
+        self.call(
+            sender,
+            self.chef_contract_hash.unwrap(),
+            TRANSFER_OWNERSHIP_ENTRY_POINT_NAME,
+            runtime_args! {
+                NEW_OWNER_KEY_NAME => new_owner
+            },
+        );
+    }
+
+    pub fn chef_accept_ownership(&mut self, sender: Sender) {
+        self.call(
+            sender,
+            self.chef_contract_hash.unwrap(),
+            ACCEPT_OWNERSHIP_ENTRY_POINT_NAME,
+            runtime_args! {},
+        );
+    }
+
+    pub fn chef_pool_count(&self) -> u32 {
+        self.context
+            .query(self.ali, &[CHEF_CONTRACT_NAME.to_string(), "pool_count".to_string()])
+            .unwrap()
+            .into_t()
+            .unwrap()
+    }
+
+    pub fn chef_stake(&mut self, pool_id: u32, amount: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.chef_contract_hash.unwrap(),
+            STAKE_ENTRY_POINT_NAME,
+            runtime_args! {
+                POOL_ID_KEY_NAME => pool_id,
+                AMOUNT_KEY_NAME => amount
+            },
+        );
+    }
+
+    pub fn chef_withdraw(&mut self, pool_id: u32, amount: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.chef_contract_hash.unwrap(),
+            WITHDRAW_ENTRY_POINT_NAME,
+            runtime_args! {
+                POOL_ID_KEY_NAME => pool_id,
+                AMOUNT_KEY_NAME => amount
+            },
+        );
+    }
+
+    pub fn chef_get_reward(&mut self, pool_id: u32, sender: Sender) {
+        self.call(
+            sender,
+            self.chef_contract_hash.unwrap(),
+            GET_REWARD_ENTRY_POINT_NAME,
+            runtime_args! {
+                POOL_ID_KEY_NAME => pool_id
+            },
+        );
+    }
+
+    pub fn chef_emergency_withdraw(&mut self, pool_id: u32, sender: Sender) {
+        self.call(
+            sender,
+            self.chef_contract_hash.unwrap(),
+            EMERGENCY_WITHDRAW_ENTRY_POINT_NAME,
+            runtime_args! {
+                POOL_ID_KEY_NAME => pool_id
+            },
+        );
+    }
+
+    pub fn propose(&mut self, target: ContractPackageHash, entry_point: &str, args: RuntimeArgs, sender: Sender) {
+        self.call(
+            sender,
+            self.governance_contract_hash.unwrap(),
+            PROPOSE_ENTRY_POINT_NAME,
+            runtime_args! {
+                TARGET_KEY_NAME => Key::from(target),
+                ENTRY_POINT_KEY_NAME => entry_point.to_string(),
+                ARGS_KEY_NAME => Bytes::from(args.to_bytes().unwrap())
+            },
+        );
+    }
+
+    pub fn vote(&mut self, proposal_id: u32, support: bool, sender: Sender) {
+        self.call(
+            sender,
+            self.governance_contract_hash.unwrap(),
+            VOTE_ENTRY_POINT_NAME,
+            runtime_args! {
+                PROPOSAL_ID_KEY_NAME => proposal_id,
+                SUPPORT_KEY_NAME => support
+            },
+        );
+    }
+
+    pub fn execute(&mut self, proposal_id: u32, sender: Sender) {
+        self.call(
+            sender,
+            self.governance_contract_hash.unwrap(),
+            EXECUTE_ENTRY_POINT_NAME,
+            runtime_args! {
+                PROPOSAL_ID_KEY_NAME => proposal_id
+            },
+        );
+    }
+
+    /// Calls every view entry point of the staking contract for `address`
+    pub fn call_views(&mut self, address: Address, sender: Sender) {
+        let reward_token = Key::from(self.reward_contract_hash);
+
+        for view in [
+            BALANCE_OF_ENTRY_POINT_NAME,
+            EFFECTIVE_BALANCE_OF_ENTRY_POINT_NAME,
+            LOCKED_BALANCE_OF_ENTRY_POINT_NAME,
+            REMAINING_CAPACITY_ENTRY_POINT_NAME,
+        ] {
+            self.call(
+                sender,
+                self.staking_contract_hash,
+                view,
+                runtime_args! {
+                    ADDRESS_KEY_NAME => address
+                },
+            );
+        }
+
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            EARNED_ENTRY_POINT_NAME,
+            runtime_args! {
+                ADDRESS_KEY_NAME => address,
+                REWARD_TOKEN_KEY_NAME => reward_token
+            },
+        );
+
+        for view in [
+            REWARD_PER_TOKEN_ENTRY_POINT_NAME,
+            REWARD_RATE_ENTRY_POINT_NAME,
+            LAST_TIME_REWARD_APPLICABLE_ENTRY_POINT_NAME,
+            EMISSION_RATES_ENTRY_POINT_NAME,
+        ] {
+            self.call(
+                sender,
+                self.staking_contract_hash,
+                view,
+                runtime_args! {
+                    REWARD_TOKEN_KEY_NAME => reward_token
+                },
+            );
+        }
+
+        for view in [
+            TOTAL_SUPPLY_ENTRY_POINT_NAME,
+            REWARD_TOKENS_ENTRY_POINT_NAME,
+            STAKE_LIMITS_ENTRY_POINT_NAME,
+        ] {
+            self.call(
+                sender,
+                self.staking_contract_hash,
+                view,
+                runtime_args! {},
+            );
+        }
+    }
+
+    /// Calls the snapshot views, they revert unless `timestamp` is in the past
+    pub fn call_snapshot_views(&mut self, address: Address, timestamp: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            BALANCE_OF_AT_ENTRY_POINT_NAME,
+            runtime_args! {
+                ADDRESS_KEY_NAME => address,
+                TIMESTAMP_KEY_NAME => timestamp
+            },
+        );
+
+        self.call(
+            sender,
+            self.staking_contract_hash,
+            TOTAL_SUPPLY_AT_ENTRY_POINT_NAME,
+            runtime_args! {
+                TIMESTAMP_KEY_NAME => timestamp
+            },
+        );
+    }
+
     /*
     pub fn get_debug_msg(&self, msg: &str) -> String {
         self.query_contract(msg)